@@ -0,0 +1,262 @@
+use std::{cmp::Ordering, fmt::Display, ops::Neg};
+
+use pyinrs::{Fraction, Int};
+
+use crate::{detail, Field, Matrix, MatrixError, Vector};
+
+/// Fraction with arbitrary-precision numerator and denominator, never overflows.
+///
+/// It is always kept in lowest terms with a positive denominator.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigFraction {
+    num: Int,
+    den: Int,
+}
+
+impl BigFraction {
+    /// Create a new fraction object, 0.
+    pub fn new() -> Self {
+        Self {
+            num: Int::new(),
+            den: Int::from(1),
+        }
+    }
+
+    /// Return the absolute value of the fraction.
+    pub fn abs(&self) -> Self {
+        Self {
+            num: self.num.abs(),
+            den: self.den.clone(),
+        }
+    }
+
+    /// Return the numerator of the fraction.
+    pub fn numerator(&self) -> &Int {
+        &self.num
+    }
+
+    /// Return the denominator of the fraction, always positive.
+    pub fn denominator(&self) -> &Int {
+        &self.den
+    }
+
+    // Create a fraction from a ratio of integers, in lowest terms with a positive denominator.
+    fn from_ratio(mut num: Int, mut den: Int) -> Self {
+        if den.is_zero() {
+            panic!("Error: Divide by zero.");
+        }
+
+        if den.is_negative() {
+            num = -num;
+            den = -den;
+        }
+
+        let gcd = Int::gcd(&num.abs(), &den);
+        Self {
+            num: num / &gcd,
+            den: den / &gcd,
+        }
+    }
+}
+
+impl Default for BigFraction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),+ $(,)?) => { $(
+        impl From<$t> for BigFraction {
+            fn from(value: $t) -> Self {
+                Self {
+                    num: Int::from(value),
+                    den: Int::from(1),
+                }
+            }
+        }
+
+        impl From<($t, $t)> for BigFraction {
+            fn from(value: ($t, $t)) -> Self {
+                Self::from_ratio(Int::from(value.0), Int::from(value.1))
+            }
+        }
+    )+ };
+}
+
+impl_from_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl From<Int> for BigFraction {
+    fn from(value: Int) -> Self {
+        Self { num: value, den: Int::from(1) }
+    }
+}
+
+impl From<(Int, Int)> for BigFraction {
+    fn from(value: (Int, Int)) -> Self {
+        Self::from_ratio(value.0, value.1)
+    }
+}
+
+impl From<Fraction> for BigFraction {
+    fn from(value: Fraction) -> Self {
+        // unsigned_abs avoids the overflow of negating i128::MIN
+        let int = |n: i128| if n < 0 { -Int::from(n.unsigned_abs()) } else { Int::from(n.unsigned_abs()) };
+        Self {
+            num: int(value.numerator()),
+            den: int(value.denominator()),
+        }
+    }
+}
+
+impl TryFrom<BigFraction> for Fraction {
+    type Error = MatrixError;
+
+    fn try_from(value: BigFraction) -> Result<Self, Self::Error> {
+        let max = Int::from(i128::MAX);
+        if value.num.abs() > max || value.den > max {
+            return Err(MatrixError::Overflow);
+        }
+
+        Ok(Fraction::from((value.num.to_number::<i128>(), value.den.to_number::<i128>())))
+    }
+}
+
+impl From<BigFraction> for f64 {
+    fn from(value: BigFraction) -> Self {
+        // the numerator and the denominator alone may be out of the range of f64, so divide |num| 2^k by den
+        // to an integer quotient of about 70 significant bits first, and then scale the quotient back by 2^-k
+        let bits = |n: &Int| (n.digits() as f64 * std::f64::consts::LOG2_10) as i64;
+        let k = 70 - (bits(&value.num) - bits(&value.den));
+        let sign = if value.num.is_negative() { -1.0 } else { 1.0 };
+        if value.num.is_zero() || k > 1200 {
+            return sign * 0.0;
+        } else if k < -1200 {
+            return sign * f64::INFINITY;
+        }
+
+        let (mut num, mut den) = (value.num.abs(), value.den);
+        let power = Int::pow(&Int::from(2), &Int::from(k.abs()));
+        if k > 0 {
+            num *= &power;
+        } else {
+            den *= &power;
+        }
+        num /= &den;
+
+        // 2^-k may be out of the range of f64 itself, so scale in steps
+        let mut float = num.to_number::<i128>() as f64;
+        let mut k = k as i32;
+        while k.abs() > 1000 {
+            let step = 1000 * k.signum();
+            float *= 2f64.powi(-step);
+            k -= step;
+        }
+        sign * float * 2f64.powi(-k)
+    }
+}
+
+impl PartialOrd for BigFraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigFraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // both denominators are positive, so compare a*d with c*b
+        (&self.num * &other.den).cmp(&(&other.num * &self.den))
+    }
+}
+
+impl Display for BigFraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.den == Int::from(1) {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Neg for BigFraction {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { num: -self.num, den: self.den }
+    }
+}
+
+impl Neg for &BigFraction {
+    type Output = BigFraction;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+auto_ops::impl_op_ex!(+|a: &BigFraction, b: &BigFraction| -> BigFraction {
+    BigFraction::from_ratio(&a.num * &b.den + &a.den * &b.num, &a.den * &b.den)
+});
+
+auto_ops::impl_op_ex!(-|a: &BigFraction, b: &BigFraction| -> BigFraction { BigFraction::from_ratio(&a.num * &b.den - &a.den * &b.num, &a.den * &b.den) });
+
+auto_ops::impl_op_ex!(*|a: &BigFraction, b: &BigFraction| -> BigFraction { BigFraction::from_ratio(&a.num * &b.num, &a.den * &b.den) });
+
+auto_ops::impl_op_ex!(/|a: &BigFraction, b: &BigFraction| -> BigFraction {
+    BigFraction::from_ratio(&a.num * &b.den, &a.den * &b.num)
+});
+
+auto_ops::impl_op_ex!(+=|a: &mut BigFraction, b: &BigFraction| { *a = &*a + b; });
+
+auto_ops::impl_op_ex!(-=|a: &mut BigFraction, b: &BigFraction| { *a = &*a - b; });
+
+auto_ops::impl_op_ex!(*=|a: &mut BigFraction, b: &BigFraction| { *a = &*a * b; });
+
+auto_ops::impl_op_ex!(/=|a: &mut BigFraction, b: &BigFraction| { *a = &*a / b; });
+
+impl Field for BigFraction {
+    fn zero() -> Self {
+        Self::new()
+    }
+
+    fn one() -> Self {
+        Self::from(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+}
+
+detail::impl_scalar_mul!(BigFraction);
+
+impl From<Vector> for Vector<BigFraction> {
+    fn from(value: Vector) -> Self {
+        value.map(|&e| BigFraction::from(e))
+    }
+}
+
+impl TryFrom<Vector<BigFraction>> for Vector {
+    type Error = MatrixError;
+
+    fn try_from(value: Vector<BigFraction>) -> Result<Self, Self::Error> {
+        let elements = value.into_iter().map(Fraction::try_from).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from(elements))
+    }
+}
+
+impl From<Matrix> for Matrix<BigFraction> {
+    fn from(value: Matrix) -> Self {
+        value.map(|&e| BigFraction::from(e))
+    }
+}
+
+impl TryFrom<Matrix<BigFraction>> for Matrix {
+    type Error = MatrixError;
+
+    fn try_from(value: Matrix<BigFraction>) -> Result<Self, Self::Error> {
+        let rows = value.into_iter().map(Vector::try_from).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { rows })
+    }
+}
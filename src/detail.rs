@@ -1,33 +1,123 @@
-use crate::Matrix;
+use crate::{Field, Matrix, MatrixError};
 
 // Check whether the index is valid (begin <= pos < end).
 #[inline]
-pub fn check_bounds(pos: usize, begin: usize, end: usize) {
+pub fn check_bounds(pos: usize, begin: usize, end: usize) -> Result<(), MatrixError> {
     if pos < begin || pos >= end {
-        panic!("Error: Index out of range.");
+        return Err(MatrixError::IndexOutOfRange { index: pos, begin, end });
     }
+    Ok(())
 }
 
 // Check whether is not empty.
 #[inline]
-pub fn check_empty(size: usize) {
+pub fn check_empty(size: usize) -> Result<(), MatrixError> {
     if size == 0 {
-        panic!("Error: The container is empty.");
+        return Err(MatrixError::Empty);
     }
+    Ok(())
 }
 
 // Check that two vectors are of the same size.
 #[inline]
-pub fn check_size(s1: usize, s2: usize) {
+pub fn check_size(s1: usize, s2: usize) -> Result<(), MatrixError> {
     if s1 != s2 {
-        panic!("Error: The dimensions mismatch.");
+        return Err(MatrixError::SizeMismatch { lhs: s1, rhs: s2 });
     }
+    Ok(())
+}
+
+// Check that two shapes (rows, cols) are the same.
+#[inline]
+pub fn check_shape(s1: (usize, usize), s2: (usize, usize)) -> Result<(), MatrixError> {
+    if s1 != s2 {
+        return Err(MatrixError::ShapeMismatch { lhs: s1, rhs: s2 });
+    }
+    Ok(())
 }
 
 // Check if the matrix is a square matrix.
 #[inline]
-pub fn check_square(m: &Matrix) {
+pub fn check_square<T: Field>(m: &Matrix<T>) -> Result<(), MatrixError> {
     if m.row_size() != m.col_size() {
-        panic!("Error: The matrix is not a square matrix.");
+        return Err(MatrixError::NotSquare { shape: m.shape() });
     }
+    Ok(())
+}
+
+// Unwrap the result of a fallible operation, panic with the error message if failed.
+#[inline]
+pub fn unwrap<T>(result: Result<T, MatrixError>) -> T {
+    result.unwrap_or_else(|e| panic!("{e}"))
+}
+
+// Implement a binary operator for all combinations of owned and borrowed operands,
+// forwarding to the implementation for two borrowed operands.
+macro_rules! forward_binop {
+    (impl<$T:ident> $Op:ident, $op:ident for $Lhs:ty, $Rhs:ty => $Out:ty) => {
+        impl<$T: Field> $Op<$Rhs> for $Lhs {
+            type Output = $Out;
+
+            fn $op(self, rhs: $Rhs) -> Self::Output {
+                (&self).$op(&rhs)
+            }
+        }
+
+        impl<$T: Field> $Op<&$Rhs> for $Lhs {
+            type Output = $Out;
+
+            fn $op(self, rhs: &$Rhs) -> Self::Output {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl<$T: Field> $Op<$Rhs> for &$Lhs {
+            type Output = $Out;
+
+            fn $op(self, rhs: $Rhs) -> Self::Output {
+                self.$op(&rhs)
+            }
+        }
+    };
 }
+
+pub(crate) use forward_binop;
+
+// Implement the scalar multiplication with the scalar on the left for a concrete element type.
+macro_rules! impl_scalar_mul {
+    ($T:ty) => {
+        impl std::ops::Mul<&$crate::Vector<$T>> for $T {
+            type Output = $crate::Vector<$T>;
+
+            fn mul(self, rhs: &$crate::Vector<$T>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl std::ops::Mul<$crate::Vector<$T>> for $T {
+            type Output = $crate::Vector<$T>;
+
+            fn mul(self, rhs: $crate::Vector<$T>) -> Self::Output {
+                &rhs * self
+            }
+        }
+
+        impl std::ops::Mul<&$crate::Matrix<$T>> for $T {
+            type Output = $crate::Matrix<$T>;
+
+            fn mul(self, rhs: &$crate::Matrix<$T>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl std::ops::Mul<$crate::Matrix<$T>> for $T {
+            type Output = $crate::Matrix<$T>;
+
+            fn mul(self, rhs: $crate::Matrix<$T>) -> Self::Output {
+                rhs * self
+            }
+        }
+    };
+}
+
+pub(crate) use impl_scalar_mul;
@@ -0,0 +1,119 @@
+use pyinrs::Fraction;
+
+use crate::{Field, Matrix};
+
+/// Reduced row echelon form of a matrix, together with its echelon structure.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rref<T = Fraction> {
+    /// The reduced row echelon form, non-zero rows first.
+    pub matrix: Matrix<T>,
+
+    /// The pivot column indices in increasing order, i.e. the column rank profile.
+    /// The i-th non-zero row of `matrix` has its pivot 1 in column `pivots[i]`.
+    pub pivots: Vec<usize>,
+
+    /// The free column indices (columns without pivot) in increasing order, i.e. the free variables of `A x = 0`.
+    pub free: Vec<usize>,
+}
+
+impl<T> Rref<T> {
+    /// Return the rank of the matrix, the number of pivots.
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// Transform this matrix to reduced row echelon form, and track the pivot and free columns.
+    pub fn rref(&self) -> Rref<T> {
+        let (matrix, pivots) = self.gauss_jordan(self.col_size());
+        let free = (0..self.col_size()).filter(|c| !pivots.contains(c)).collect();
+        Rref { matrix, pivots, free }
+    }
+
+    // Gauss-Jordan elimination on the first n columns, return the reduced matrix and the pivot columns.
+    pub(crate) fn gauss_jordan(&self, n: usize) -> (Self, Vec<usize>) {
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+
+        for c in 0..n {
+            let r = pivots.len();
+            if r == m.row_size() {
+                break;
+            }
+
+            // find a row with non-zero element in column c as the pivot row
+            let Some(p) = (r..m.row_size()).find(|&i| !m[i][c].is_zero()) else {
+                continue;
+            };
+            m.e_row_swap(r, p);
+            m.e_scalar_multiplication(r, m[r][c].one_like() / m[r][c].clone());
+
+            // eliminate elements above and below the pivot
+            for i in 0..m.row_size() {
+                if i != r && !m[i][c].is_zero() {
+                    m.e_row_sum(i, r, -m[i][c].clone());
+                }
+            }
+            pivots.push(c);
+        }
+
+        (m, pivots)
+    }
+
+    // Bareiss fraction-free elimination, return the echelon matrix, the pivot columns and whether the number of row swaps is odd.
+    // All divisions are exact, so an integer matrix stays integer, and every element is a minor of the original matrix.
+    pub(crate) fn bareiss(&self) -> (Self, Vec<usize>, bool) {
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+        let mut odd = false;
+        let mut prev = self.one_like();
+
+        for c in 0..m.col_size() {
+            let r = pivots.len();
+            if r == m.row_size() {
+                break;
+            }
+
+            // find a row with non-zero element in column c as the pivot row
+            let Some(p) = (r..m.row_size()).find(|&i| !m[i][c].is_zero()) else {
+                continue;
+            };
+            if p != r {
+                m.e_row_swap(r, p);
+                odd = !odd;
+            }
+
+            // M[i][j] = (M[r][c] * M[i][j] - M[i][c] * M[r][j]) / prev, the division is exact
+            for i in r + 1..m.row_size() {
+                for j in c + 1..m.col_size() {
+                    let e = m[r][c].clone() * m[i][j].clone() - m[i][c].clone() * m[r][j].clone();
+                    m[i][j] = e / prev.clone();
+                }
+                m[i][c] = m[i][c].zero_like();
+            }
+
+            prev = m[r][c].clone();
+            pivots.push(c);
+        }
+
+        (m, pivots, odd)
+    }
+}
+
+impl Matrix {
+    /// Transform this matrix to fraction-free row echelon form, use Bareiss algorithm.
+    ///
+    /// The matrix is first scaled by the least common denominator of its elements, so the result always has integer elements,
+    /// and every element is a minor of the scaled matrix. Non-zero rows come first, zero rows last.
+    pub fn fraction_free_form(&self) -> Self {
+        let mut lcm = Fraction::from(1);
+        for row in &self.rows {
+            for e in &row.elements {
+                lcm = Fraction::lcm(lcm, e.denominator().into());
+            }
+        }
+
+        (self.clone() * lcm).bareiss().0
+    }
+}
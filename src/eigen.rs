@@ -0,0 +1,126 @@
+use pyinrs::Fraction;
+
+use crate::{detail, Matrix, MatrixError, Polynomial, Vector};
+
+impl Matrix {
+    /// Calculate the characteristic polynomial `det(xI - A)` of this matrix.
+    ///
+    /// It is monic of degree n, the coefficient of `x^(n-1)` is `-trace`, and the constant term is `(-1)^n * det`.
+    pub fn char_poly(&self) -> Polynomial {
+        detail::unwrap(self.try_char_poly())
+    }
+
+    /// Calculate the characteristic polynomial `det(xI - A)` of this matrix, or an error if it is not square.
+    pub fn try_char_poly(&self) -> Result<Polynomial, MatrixError> {
+        detail::check_square(self)?;
+
+        // Berkowitz algorithm, division-free: the characteristic polynomial of the leading (r+1) x (r+1) submatrix
+        // is T * p, where p is that of the leading r x r submatrix A_r and T is the lower triangular Toeplitz matrix
+        // whose first column is (1, -a, -R C, -R A_r C, ..., -R A_r^(r-1) C), with a = A[r][r],
+        // R = A[r][0..r] the row to its left, and C = A[0..r][r] the column above it.
+        // coefficients are kept in descending order of degree
+        let dot = |a: &[Fraction], b: &[Fraction]| a.iter().zip(b).fold(Fraction::new(), |acc, (&x, &y)| acc + x * y);
+        let mut p = vec![Fraction::from(1)];
+        for r in 0..self.row_size() {
+            let mut t = vec![Fraction::from(1), -self[r][r]];
+            let mut v = (0..r).map(|i| self[i][r]).collect::<Vec<_>>(); // A_r^k C
+            for _ in 0..r {
+                t.push(-dot(&self[r].elements[..r], &v));
+                v = (0..r).map(|i| dot(&self[i].elements[..r], &v)).collect();
+            }
+
+            let mut q = vec![Fraction::new(); r + 2];
+            for (i, e) in q.iter_mut().enumerate() {
+                for j in 0..=i.min(r) {
+                    *e += t[i - j] * p[j];
+                }
+            }
+            p = q;
+        }
+
+        p.reverse();
+        Ok(Polynomial::from(p))
+    }
+
+    /// Return the rational eigenvalues in increasing order, each with its algebraic multiplicity.
+    ///
+    /// These are the rational roots of the characteristic polynomial. Irrational and complex eigenvalues are not returned,
+    /// so the multiplicities sum to n only if the whole spectrum is rational.
+    pub fn rational_eigenvalues(&self) -> Vec<(Fraction, usize)> {
+        detail::unwrap(self.try_rational_eigenvalues())
+    }
+
+    /// Return the rational eigenvalues in increasing order, each with its algebraic multiplicity,
+    /// or an error if the matrix is not square or its characteristic polynomial cannot be factored.
+    pub fn try_rational_eigenvalues(&self) -> Result<Vec<(Fraction, usize)>, MatrixError> {
+        self.try_char_poly()?.try_rational_roots()
+    }
+
+    /// Return a basis of the eigenspace `ker(A - λI)` of the eigenvalue λ, empty if λ is not an eigenvalue.
+    pub fn eigenvectors(&self, lambda: Fraction) -> Vec<Vector> {
+        detail::unwrap(self.try_eigenvectors(lambda))
+    }
+
+    /// Return a basis of the eigenspace `ker(A - λI)` of the eigenvalue λ, or an error if the matrix is not square.
+    pub fn try_eigenvectors(&self, lambda: Fraction) -> Result<Vec<Vector>, MatrixError> {
+        detail::check_square(self)?;
+
+        Ok((self - Self::identity(self.row_size()) * lambda).null_space())
+    }
+
+    /// Returns `true` if the matrix is diagonalizable over the rationals.
+    ///
+    /// The minimal polynomial is square-free, i.e. it has no repeated roots, and all of its roots are rational. Returns `false`
+    /// if the matrix is not square, or the roots cannot be found, see [`Polynomial::try_rational_roots`].
+    pub fn is_diagonalizable(&self) -> bool {
+        if self.row_size() != self.col_size() {
+            return false;
+        }
+
+        let p = self.min_poly();
+        p.square_free() == p && p.try_rational_roots().is_ok_and(|roots| roots.len() == p.degree().unwrap_or(0))
+    }
+
+    /// Diagonalize the matrix over the rationals, return (P, D) such that `A = P D P^-1`, with D diagonal.
+    ///
+    /// Return `None` if the matrix is not diagonalizable over the rationals.
+    pub fn diagonalize(&self) -> Option<(Self, Self)> {
+        match self.try_diagonalize() {
+            Ok(pd) => Some(pd),
+            Err(MatrixError::NonRationalEigenvalue | MatrixError::Defective { .. }) => None,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Diagonalize the matrix over the rationals, return (P, D) such that `A = P D P^-1`, with D diagonal,
+    /// or an error if it is not square, has a non-rational eigenvalue, or is defective.
+    ///
+    /// The eigenvalues are on the diagonal of D in increasing order, the columns of P are the corresponding eigenvectors.
+    pub fn try_diagonalize(&self) -> Result<(Self, Self), MatrixError> {
+        detail::check_square(self)?;
+
+        let n = self.row_size();
+        let eigenvalues = self.try_rational_eigenvalues()?;
+        if eigenvalues.iter().map(|&(_, m)| m).sum::<usize>() != n {
+            return Err(MatrixError::NonRationalEigenvalue);
+        }
+
+        let (mut columns, mut diagonal) = (Vec::with_capacity(n), Vec::with_capacity(n));
+        for (lambda, m) in eigenvalues {
+            let vectors = self.eigenvectors(lambda);
+            if vectors.len() < m {
+                return Err(MatrixError::Defective {
+                    eigenvalue: lambda,
+                    algebraic: m,
+                    geometric: vectors.len(),
+                });
+            }
+            columns.extend(vectors);
+            diagonal.extend(std::iter::repeat_n(lambda, m));
+        }
+
+        let p = Self { rows: columns }.transpose();
+        let d = Self::from_fn(n, n, |i, j| if i == j { diagonal[i] } else { Fraction::new() });
+        Ok((p, d))
+    }
+}
@@ -0,0 +1,87 @@
+use std::{error::Error, fmt::Display};
+
+use pyinrs::Fraction;
+
+/// Errors that can occur in vector and matrix operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MatrixError {
+    /// The index is out of range (begin <= index < end).
+    IndexOutOfRange { index: usize, begin: usize, end: usize },
+
+    /// The container is empty.
+    Empty,
+
+    /// The sizes of two vectors mismatch.
+    SizeMismatch { lhs: usize, rhs: usize },
+
+    /// The shapes (rows, cols) of two matrices mismatch.
+    ShapeMismatch { lhs: (usize, usize), rhs: (usize, usize) },
+
+    /// The matrix is not a square matrix, with its shape (rows, cols).
+    NotSquare { shape: (usize, usize) },
+
+    /// The cross product is only defined for two 2-dimensional or two 3-dimensional vectors.
+    IncompatibleCross { lhs: usize, rhs: usize },
+
+    /// The matrix is singular.
+    Singular,
+
+    /// A zero pivot at the given index was encountered where a division is required.
+    ZeroPivot { index: usize },
+
+    /// The value does not fit in the fixed-width target type.
+    Overflow,
+
+    /// The value is not invertible modulo the given modulus.
+    NotInvertible { modulus: u64 },
+
+    /// The matrix has an eigenvalue that is not rational.
+    NonRationalEigenvalue,
+
+    /// The geometric multiplicity of the eigenvalue is less than its algebraic multiplicity.
+    Defective { eigenvalue: Fraction, algebraic: usize, geometric: usize },
+
+    /// The element at (row, col) is not an integer where an integer matrix is required.
+    NotInteger { row: usize, col: usize },
+
+    /// The vectors are linearly dependent where a basis is required.
+    LinearlyDependent,
+
+    /// The matrix is not symmetric where a symmetric matrix is required.
+    NotSymmetric,
+
+    /// The modulus is zero where a positive modulus is required.
+    ZeroModulus,
+
+    /// The LLL parameter δ is not in `(1/4, 1]`.
+    InvalidDelta { delta: Fraction },
+
+    /// The divisors of the integer that may give rational roots take more than 2^22 trial divisions to find.
+    FactorizationLimit { value: i128 },
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::IndexOutOfRange { .. } => write!(f, "Error: Index out of range."),
+            Self::Empty => write!(f, "Error: The container is empty."),
+            Self::SizeMismatch { .. } | Self::ShapeMismatch { .. } => write!(f, "Error: The dimensions mismatch."),
+            Self::NotSquare { .. } => write!(f, "Error: The matrix is not a square matrix."),
+            Self::IncompatibleCross { .. } => write!(f, "Error: Incompatible dimensions for cross product."),
+            Self::Singular => write!(f, "Error: The matrix is singular."),
+            Self::ZeroPivot { .. } => write!(f, "Error: Zero pivot encountered."),
+            Self::Overflow => write!(f, "Error: Value out of range."),
+            Self::NotInvertible { .. } => write!(f, "Error: The value is not invertible modulo the modulus."),
+            Self::NonRationalEigenvalue => write!(f, "Error: The matrix has a non-rational eigenvalue."),
+            Self::Defective { .. } => write!(f, "Error: The matrix is defective."),
+            Self::NotInteger { .. } => write!(f, "Error: The matrix has a non-integer element."),
+            Self::LinearlyDependent => write!(f, "Error: The vectors are linearly dependent."),
+            Self::NotSymmetric => write!(f, "Error: The matrix is not symmetric."),
+            Self::ZeroModulus => write!(f, "Error: The modulus is zero."),
+            Self::InvalidDelta { .. } => write!(f, "Error: The parameter delta must be in (1/4, 1]."),
+            Self::FactorizationLimit { .. } => write!(f, "Error: The divisors of the integer are too many to search."),
+        }
+    }
+}
+
+impl Error for MatrixError {}
@@ -0,0 +1,89 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use pyinrs::Fraction;
+
+/// Element type of vectors and matrices: a field, with the operations the algorithms need.
+///
+/// Implemented for [`Fraction`] (the default element type) and `f64`. To use a number type of your own,
+/// implement the arithmetic operators and this trait for it:
+///
+/// ```
+/// use mymatrix::{Field, Matrix};
+/// use std::ops::*;
+///
+/// // integers modulo 5
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Mod5(u8);
+///
+/// impl Add for Mod5 { type Output = Self; fn add(self, rhs: Self) -> Self { Mod5((self.0 + rhs.0) % 5) } }
+/// impl Sub for Mod5 { type Output = Self; fn sub(self, rhs: Self) -> Self { Mod5((self.0 + 5 - rhs.0) % 5) } }
+/// impl Mul for Mod5 { type Output = Self; fn mul(self, rhs: Self) -> Self { Mod5(self.0 * rhs.0 % 5) } }
+/// impl Div for Mod5 { type Output = Self; fn div(self, rhs: Self) -> Self { self * Mod5(rhs.0.pow(3) % 5) } }
+/// impl Neg for Mod5 { type Output = Self; fn neg(self) -> Self { Mod5((5 - self.0) % 5) } }
+/// impl AddAssign for Mod5 { fn add_assign(&mut self, rhs: Self) { *self = *self + rhs } }
+/// impl SubAssign for Mod5 { fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs } }
+/// impl MulAssign for Mod5 { fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs } }
+/// impl DivAssign for Mod5 { fn div_assign(&mut self, rhs: Self) { *self = *self / rhs } }
+///
+/// impl Field for Mod5 {
+///     fn zero() -> Self { Mod5(0) }
+///     fn one() -> Self { Mod5(1) }
+/// }
+///
+/// let m = Matrix::from([[Mod5(1), Mod5(2)], [Mod5(3), Mod5(4)]]);
+/// assert_eq!(m.det(), Mod5(3)); // 1*4 - 2*3 = -2 = 3 (mod 5)
+/// assert_eq!(&m * &m.inv().unwrap(), Matrix::scalar(2, Mod5(1)));
+/// ```
+pub trait Field:
+    Clone + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self> + AddAssign + SubAssign + MulAssign + DivAssign
+{
+    /// Return the additive identity.
+    fn zero() -> Self;
+
+    /// Return the multiplicative identity.
+    fn one() -> Self;
+
+    /// Returns `true` if this is the additive identity.
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// Return the additive identity of the same kind as this element.
+    ///
+    /// The algorithms build new elements from the elements of their input with this, so that a type whose elements carry
+    /// runtime data, like the modulus of [`GfDyn`](crate::GfDyn), can keep it. Defaults to [`Field::zero`].
+    fn zero_like(&self) -> Self {
+        Self::zero()
+    }
+
+    /// Return the multiplicative identity of the same kind as this element, see [`Field::zero_like`]. Defaults to [`Field::one`].
+    fn one_like(&self) -> Self {
+        Self::one()
+    }
+}
+
+impl Field for Fraction {
+    fn zero() -> Self {
+        Fraction::new()
+    }
+
+    fn one() -> Self {
+        Fraction::from(1)
+    }
+}
+
+crate::detail::impl_scalar_mul!(Fraction);
+
+/// Floating-point elements, compared exactly: results are subject to rounding errors,
+/// and a pivot that should vanish may not be recognized as zero.
+impl Field for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+}
+
+crate::detail::impl_scalar_mul!(f64);
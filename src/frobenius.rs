@@ -0,0 +1,145 @@
+use pyinrs::Fraction;
+
+use crate::{detail, Matrix, MatrixError, Polynomial, Solution, Vector};
+
+/// Frobenius normal form (rational canonical form) `A = P F P^-1` of a matrix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Frobenius {
+    /// The transformation matrix, its columns are bases `v, A v, ..., A^(d-1) v` of cyclic subspaces.
+    pub p: Matrix,
+
+    /// The Frobenius matrix, block diagonal with the companion matrices of the invariant factors.
+    pub f: Matrix,
+
+    /// The non-trivial invariant factors, monic, each one divides the next, the last one is the minimal polynomial.
+    pub invariant_factors: Vec<Polynomial>,
+}
+
+// Return the companion matrix of the monic polynomial: ones on the subdiagonal, minus the coefficients in the last column.
+fn companion(p: &Polynomial) -> Matrix {
+    let d = p.degree().unwrap_or(0);
+    let mut c = Matrix::zeros(d, d);
+    for i in 0..d {
+        if i + 1 < d {
+            c[i + 1][i] = 1.into();
+        }
+        c[i][d - 1] = -p.coeff(i);
+    }
+    c
+}
+
+// Return the Krylov vectors `v, f(v), ..., f^(d-1)(v)` and the monic polynomial p of degree d with `sum p_i f^i(v) = 0`,
+// where `f^d(v)` is the first vector that is a linear combination of the previous ones.
+pub(crate) fn krylov(v: Vector, f: impl Fn(&Vector) -> Vector) -> (Vec<Vector>, Polynomial) {
+    let mut vectors: Vec<Vector> = Vec::new();
+    let mut next = v;
+    loop {
+        // the previous vectors are independent, so a dependency is unique up to a factor and the new vector is free
+        vectors.push(next);
+        let kernel = Matrix { rows: vectors.clone() }.transpose().null_space();
+        if let Some(p) = kernel.into_iter().next() {
+            vectors.pop();
+            return (vectors, Polynomial::from(p.into_iter().collect::<Vec<_>>()));
+        }
+        next = f(vectors.last().unwrap());
+    }
+}
+
+// Decompose Q^n into cyclic subspaces of A, return the invariant factors, each one dividing the next, and the bases
+// `v, A v, ..., A^(d-1) v` of the cyclic subspaces in the same order, concatenated.
+//
+// Split off the cyclic subspace Z of a vector v whose minimal polynomial is the minimal polynomial μ of A, of degree d.
+// Take a linear form f with f(A^i v) = 0 for i < d - 1 and f(A^(d-1) v) = 1, then W = {x : f(A^i x) = 0 for i < d} is
+// invariant since μ(A) = 0, and meets Z only in 0 since the Hankel matrix f(A^(i+j) v) is invertible. So Q^n = W + Z,
+// and A restricted to W has the other invariant factors. Return an error if a point of the moment curve overflows.
+fn cyclic_decomposition(a: &Matrix) -> Result<(Vec<Polynomial>, Vec<Vector>), MatrixError> {
+    let n = a.row_size();
+    if n == 0 {
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    // the vectors whose minimal polynomial is a proper divisor of μ lie in the kernels of (μ / p)(A) for the irreducible
+    // factors p of μ, at most n proper subspaces, and each one meets the moment curve (1, c, c^2, ...) in less than n points
+    let mu = a.min_poly();
+    let d = mu.degree().unwrap();
+    let unit = |i: usize| Ok(Vector::from_fn(n, |j| Fraction::from((i == j) as i32)));
+    let moment_curve = |c: i128| {
+        (0..n)
+            .map(|j| c.checked_pow(j as u32).map(Fraction::from).ok_or(MatrixError::Overflow))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Vector::from)
+    };
+    let mut z = Vec::new();
+    for v in (0..n).map(unit).chain((1..).map(moment_curve)) {
+        z = krylov(v?, |x| a.apply(x)).0;
+        if z.len() == d {
+            break;
+        }
+    }
+    if d == n {
+        return Ok((vec![mu], z));
+    }
+
+    // the forms f A^i are the equations of W
+    let f = match (Matrix { rows: z.clone() }).solve(&Vector::from_fn(d, |i| Fraction::from((i == d - 1) as i32))) {
+        Solution::Unique(f) | Solution::Infinite { particular: f, .. } => f,
+        Solution::NoSolution => unreachable!("the vectors of Z are independent"),
+    };
+    let t = a.transpose();
+    let forms = Matrix {
+        rows: std::iter::successors(Some(f), |f| Some(t.apply(f))).take(d).collect(),
+    };
+    let w = Matrix { rows: forms.null_space() }.transpose();
+
+    // A restricted to W in the basis w is the upper left block of Q^-1 A Q, with Q = [w z]
+    let m = n - d;
+    let mut q = w.clone();
+    q.expand_col(Matrix { rows: z.clone() }.transpose());
+    let b = q.try_inv()? * a * &q;
+    let (mut invariant_factors, basis) = cyclic_decomposition(&Matrix::from_fn(m, m, |i, j| b[i][j]))?;
+
+    let mut basis = basis.iter().map(|u| w.apply(u)).collect::<Vec<_>>();
+    invariant_factors.push(mu);
+    basis.extend(z);
+    Ok((invariant_factors, basis))
+}
+
+impl Matrix {
+    /// Calculate the Frobenius normal form (rational canonical form) `A = P F P^-1` of this matrix.
+    pub fn frobenius_form(&self) -> Frobenius {
+        detail::unwrap(self.try_frobenius_form())
+    }
+
+    /// Calculate the Frobenius normal form (rational canonical form) `A = P F P^-1` of this matrix, or an error if it is not square
+    /// or the candidates for a cyclic vector overflow.
+    ///
+    /// The invariant factors are the non-trivial diagonal elements of the Smith normal form of `xI - A` over Q[x]. They are
+    /// found by exact elimination over Q, splitting off one cyclic subspace of the largest invariant factor at a time.
+    pub fn try_frobenius_form(&self) -> Result<Frobenius, MatrixError> {
+        detail::check_square(self)?;
+
+        let (invariant_factors, columns) = cyclic_decomposition(self)?;
+        let p = Self { rows: columns }.transpose();
+        let mut f = Self::zeros(self.row_size(), self.col_size());
+        let mut offset = 0;
+        for c in invariant_factors.iter().map(companion) {
+            for i in 0..c.row_size() {
+                for j in 0..c.col_size() {
+                    f[offset + i][offset + j] = c[i][j];
+                }
+            }
+            offset += c.row_size();
+        }
+
+        Ok(Frobenius { p, f, invariant_factors })
+    }
+
+    /// Returns `true` if the two matrices are similar, i.e. `B = P^-1 A P` for an invertible P, by comparing invariant factors.
+    pub fn is_similar(&self, that: &Self) -> bool {
+        if self.row_size() != self.col_size() || self.shape() != that.shape() {
+            return false;
+        }
+
+        detail::unwrap(cyclic_decomposition(self)).0 == detail::unwrap(cyclic_decomposition(that)).0
+    }
+}
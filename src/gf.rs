@@ -0,0 +1,495 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use pyinrs::Fraction;
+
+use crate::{Field, Matrix, MatrixError, Vector};
+
+// Return a * b mod m without overflow.
+pub(crate) const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+// Return base ^ exp mod m, use binary exponentiation.
+const fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+// Check whether n is a prime, use Miller-Rabin test with bases that are deterministic for all 64-bit integers.
+pub(crate) const fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        if n.is_multiple_of(BASES[i]) {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+
+    // n - 1 = d * 2^s with d odd
+    let (mut d, mut s) = (n - 1, 0);
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+
+    let mut i = 0;
+    'witness: while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
+
+// Reduce a fraction modulo the prime p, or an error if the denominator is divisible by p.
+fn reduce_fraction(value: Fraction, p: u64) -> Result<u64, MatrixError> {
+    let residue = |n: i128| n.rem_euclid(p as i128) as u64;
+    let den = residue(value.denominator());
+    if den == 0 {
+        return Err(MatrixError::NotInvertible { modulus: p });
+    }
+    Ok(mul_mod(residue(value.numerator()), pow_mod(den, p - 2, p), p))
+}
+
+/// Element of the prime field GF(P) = Z/PZ, with the modulus fixed at compile time.
+///
+/// Using a `P` that is not a prime is a compile-time error:
+///
+/// ```compile_fail
+/// let x = mymatrix::Gf::<15>::new(1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf<const P: u64>(u64);
+
+impl<const P: u64> Gf<P> {
+    const PRIME: () = assert!(is_prime(P), "Error: The modulus is not a prime.");
+
+    /// Create the element congruent to `value` modulo P.
+    pub fn new(value: i64) -> Self {
+        Self::from(value)
+    }
+
+    /// Return the representative of the element in `0..P`.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Return the modulus P.
+    pub fn modulus(&self) -> u64 {
+        P
+    }
+
+    /// Return the multiplicative inverse, or `None` for zero.
+    pub fn inv(&self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+
+    /// Return the element raised to the power `exp`.
+    pub fn pow(&self, exp: u64) -> Self {
+        Self(pow_mod(self.0, exp, P))
+    }
+
+    fn from_residue(residue: u64) -> Self {
+        let () = Self::PRIME;
+        Self(residue)
+    }
+}
+
+impl<const P: u64> Default for Gf<P> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),+ $(,)?) => { $(
+        impl<const P: u64> From<$t> for Gf<P> {
+            fn from(value: $t) -> Self {
+                Self::from_residue((value as i128).rem_euclid(P as i128) as u64)
+            }
+        }
+    )+ };
+}
+
+impl_from_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl<const P: u64> TryFrom<Fraction> for Gf<P> {
+    type Error = MatrixError;
+
+    fn try_from(value: Fraction) -> Result<Self, Self::Error> {
+        Ok(Self::from_residue(reduce_fraction(value, P)?))
+    }
+}
+
+impl<const P: u64> Display for Gf<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Neg for Gf<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self((P - self.0) % P)
+    }
+}
+
+impl<const P: u64> Add for Gf<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Gf<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Mul for Gf<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(mul_mod(self.0, rhs.0, P))
+    }
+}
+
+impl<const P: u64> Div for Gf<P> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.mul(rhs.inv().expect("Error: Divide by zero."))
+    }
+}
+
+impl<const P: u64> AddAssign for Gf<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> SubAssign for Gf<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for Gf<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> DivAssign for Gf<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64> Field for Gf<P> {
+    fn zero() -> Self {
+        Self::from_residue(0)
+    }
+
+    fn one() -> Self {
+        Self::from_residue(1 % P)
+    }
+}
+
+impl<const P: u64> Mul<&Vector<Gf<P>>> for Gf<P> {
+    type Output = Vector<Gf<P>>;
+
+    fn mul(self, rhs: &Vector<Gf<P>>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const P: u64> Mul<Vector<Gf<P>>> for Gf<P> {
+    type Output = Vector<Gf<P>>;
+
+    fn mul(self, rhs: Vector<Gf<P>>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const P: u64> Mul<&Matrix<Gf<P>>> for Gf<P> {
+    type Output = Matrix<Gf<P>>;
+
+    fn mul(self, rhs: &Matrix<Gf<P>>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const P: u64> Mul<Matrix<Gf<P>>> for Gf<P> {
+    type Output = Matrix<Gf<P>>;
+
+    fn mul(self, rhs: Matrix<Gf<P>>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const P: u64> TryFrom<Vector> for Vector<Gf<P>> {
+    type Error = MatrixError;
+
+    fn try_from(value: Vector) -> Result<Self, Self::Error> {
+        let elements = value.into_iter().map(Gf::try_from).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from(elements))
+    }
+}
+
+impl<const P: u64> TryFrom<Matrix> for Matrix<Gf<P>> {
+    type Error = MatrixError;
+
+    fn try_from(value: Matrix) -> Result<Self, Self::Error> {
+        let rows = value.into_iter().map(Vector::try_from).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { rows })
+    }
+}
+
+/// Element of the prime field GF(p) = Z/pZ, with the modulus chosen at runtime.
+///
+/// [`Field::zero`] and [`Field::one`] have no modulus: they are integer constants that take the modulus of the element
+/// they are combined with, so the generic algorithms work unchanged. The algorithms build new elements with [`Field::zero_like`]
+/// and [`Field::one_like`], which keep the modulus, so the results of a matrix with elements have the modulus of its elements.
+/// Combining elements of different moduli panics, and an element never equals a constant, nor an element of another modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GfDyn {
+    // the representative in 0..modulus, or any integer if modulus is 0
+    value: i128,
+    modulus: u64,
+}
+
+impl GfDyn {
+    /// Create the element congruent to `value` modulo the prime `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not a prime.
+    pub fn new(value: i64, p: u64) -> Self {
+        Self::check_prime(p);
+        Self::bound(value as i128, p)
+    }
+
+    /// Create the element congruent to the fraction `value` modulo the prime `p`, or an error if its denominator is divisible by `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not a prime.
+    pub fn try_from_fraction(value: Fraction, p: u64) -> Result<Self, MatrixError> {
+        Self::check_prime(p);
+        Ok(Self::bound(reduce_fraction(value, p)? as i128, p))
+    }
+
+    /// Return the representative of the element in `0..p`, or the value of an integer constant without modulus.
+    pub fn value(&self) -> i128 {
+        self.value
+    }
+
+    /// Return the modulus p, or `None` for an integer constant without modulus.
+    pub fn modulus(&self) -> Option<u64> {
+        (self.modulus != 0).then_some(self.modulus)
+    }
+
+    /// Return the multiplicative inverse, or `None` for zero.
+    pub fn inv(&self) -> Option<Self> {
+        match self.modulus {
+            _ if self.value == 0 => None,
+            0 => (self.value.abs() == 1).then_some(*self),
+            p => Some(Self::bound(pow_mod(self.value as u64, p - 2, p) as i128, p)),
+        }
+    }
+
+    fn check_prime(p: u64) {
+        if !is_prime(p) {
+            panic!("Error: The modulus is not a prime.");
+        }
+    }
+
+    fn bound(value: i128, p: u64) -> Self {
+        Self {
+            value: value.rem_euclid(p as i128),
+            modulus: p,
+        }
+    }
+
+    // Bring two elements to a common modulus, return their values and the modulus.
+    fn unify(self, that: Self) -> (i128, i128, u64) {
+        match (self.modulus, that.modulus) {
+            (0, 0) => (self.value, that.value, 0),
+            (p, 0) => (self.value, that.value.rem_euclid(p as i128), p),
+            (0, p) => (self.value.rem_euclid(p as i128), that.value, p),
+            (p, q) if p == q => (self.value, that.value, p),
+            _ => panic!("Error: The moduli mismatch."),
+        }
+    }
+
+    fn with(value: i128, p: u64) -> Self {
+        if p == 0 {
+            Self { value, modulus: 0 }
+        } else {
+            Self::bound(value, p)
+        }
+    }
+}
+
+impl Display for GfDyn {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Neg for GfDyn {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::with(-self.value, self.modulus)
+    }
+}
+
+impl Add for GfDyn {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (a, b, p) = self.unify(rhs);
+        Self::with(a + b, p)
+    }
+}
+
+impl Sub for GfDyn {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (a, b, p) = self.unify(rhs);
+        Self::with(a - b, p)
+    }
+}
+
+impl Mul for GfDyn {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // the values are in 0..p after unify, so multiply them in u128 as Gf<P> does, p may be close to 2^64
+        match self.unify(rhs) {
+            (a, b, 0) => Self::with(a * b, 0),
+            (a, b, p) => Self::bound(mul_mod(a as u64, b as u64, p) as i128, p),
+        }
+    }
+}
+
+impl Div for GfDyn {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let (a, b, p) = self.unify(rhs);
+        let inv = Self::with(b, p).inv().expect("Error: Divide by zero.");
+        Self::with(a, p).mul(inv)
+    }
+}
+
+impl AddAssign for GfDyn {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for GfDyn {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for GfDyn {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for GfDyn {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Field for GfDyn {
+    fn zero() -> Self {
+        Self { value: 0, modulus: 0 }
+    }
+
+    fn one() -> Self {
+        Self { value: 1, modulus: 0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::with(0, self.modulus)
+    }
+
+    fn one_like(&self) -> Self {
+        Self::with(1, self.modulus)
+    }
+}
+
+crate::detail::impl_scalar_mul!(GfDyn);
+
+impl Vector {
+    /// Reduce the elements modulo the prime `p`, or an error if a denominator is divisible by `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not a prime.
+    pub fn try_to_gf(&self, p: u64) -> Result<Vector<GfDyn>, MatrixError> {
+        let elements = self.elements.iter().map(|&e| GfDyn::try_from_fraction(e, p)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Vector::from(elements))
+    }
+}
+
+impl Matrix {
+    /// Reduce the elements modulo the prime `p`, or an error if a denominator is divisible by `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not a prime.
+    pub fn try_to_gf(&self, p: u64) -> Result<Matrix<GfDyn>, MatrixError> {
+        let rows = self.rows.iter().map(|row| row.try_to_gf(p)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Matrix { rows })
+    }
+}
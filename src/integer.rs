@@ -0,0 +1,243 @@
+use pyinrs::Fraction;
+
+use crate::{detail, Matrix, MatrixError, Solution, Vector};
+
+// Integer matrix as rows of i128, every operation is checked and reports an overflow as an error.
+type Rows = Vec<Vec<i128>>;
+
+// Convert to integer rows, or an error at the first non-integer element.
+fn to_rows(a: &Matrix) -> Result<Rows, MatrixError> {
+    let mut rows = vec![vec![0; a.col_size()]; a.row_size()];
+    for (r, row) in rows.iter_mut().enumerate() {
+        for (c, e) in row.iter_mut().enumerate() {
+            if a[r][c].denominator() != 1 {
+                return Err(MatrixError::NotInteger { row: r, col: c });
+            }
+            *e = a[r][c].numerator();
+        }
+    }
+    Ok(rows)
+}
+
+fn to_matrix(rows: Rows, col: usize) -> Matrix {
+    Matrix::from_fn(rows.len(), col, |r, c| Fraction::from(rows[r][c]))
+}
+
+fn identity(n: usize) -> Rows {
+    (0..n).map(|i| (0..n).map(|j| (i == j) as i128).collect()).collect()
+}
+
+// Floor-like division with a non-negative remainder.
+fn div(a: i128, b: i128) -> Result<i128, MatrixError> {
+    a.checked_div_euclid(b).ok_or(MatrixError::Overflow)
+}
+
+// row_i -= q * row_t
+fn sub_row(rows: &mut Rows, i: usize, t: usize, q: i128) -> Result<(), MatrixError> {
+    for c in 0..rows[i].len() {
+        let e = q.checked_mul(rows[t][c]).and_then(|e| rows[i][c].checked_sub(e)).ok_or(MatrixError::Overflow)?;
+        rows[i][c] = e;
+    }
+    Ok(())
+}
+
+// row_i = -row_i
+fn neg_row(rows: &mut Rows, i: usize) -> Result<(), MatrixError> {
+    for e in rows[i].iter_mut() {
+        *e = e.checked_neg().ok_or(MatrixError::Overflow)?;
+    }
+    Ok(())
+}
+
+// col_j -= q * col_t
+fn sub_col(rows: &mut Rows, j: usize, t: usize, q: i128) -> Result<(), MatrixError> {
+    for row in rows.iter_mut() {
+        row[j] = q.checked_mul(row[t]).and_then(|e| row[j].checked_sub(e)).ok_or(MatrixError::Overflow)?;
+    }
+    Ok(())
+}
+
+fn swap_col(rows: &mut Rows, j: usize, t: usize) {
+    rows.iter_mut().for_each(|row| row.swap(j, t));
+}
+
+// Smith normal form `U A V = S` of integer rows, return (U, S, V).
+fn smith(a: Rows, n: usize) -> Result<(Rows, Rows, Rows), MatrixError> {
+    let m = a.len();
+    let (mut u, mut s, mut v) = (identity(m), a, identity(n));
+
+    for t in 0..m.min(n) {
+        loop {
+            // move the smallest non-zero element of the rest to the pivot position, stop if the rest is zero
+            let Some((pi, pj)) = (t..m)
+                .flat_map(|i| (t..n).map(move |j| (i, j)))
+                .filter(|&(i, j)| s[i][j] != 0)
+                .min_by_key(|&(i, j)| s[i][j].unsigned_abs())
+            else {
+                return Ok((u, s, v));
+            };
+            s.swap(t, pi);
+            u.swap(t, pi);
+            swap_col(&mut s, t, pj);
+            swap_col(&mut v, t, pj);
+
+            // reduce the column below and the row right of the pivot, the remainders are smaller than the pivot
+            for i in t + 1..m {
+                let q = div(s[i][t], s[t][t])?;
+                sub_row(&mut s, i, t, q)?;
+                sub_row(&mut u, i, t, q)?;
+            }
+            for j in t + 1..n {
+                let q = div(s[t][j], s[t][t])?;
+                sub_col(&mut s, j, t, q)?;
+                sub_col(&mut v, j, t, q)?;
+            }
+            if (t + 1..m).any(|i| s[i][t] != 0) || (t + 1..n).any(|j| s[t][j] != 0) {
+                continue;
+            }
+
+            // the pivot must divide every element of the rest, otherwise add that row and reduce again
+            match (t + 1..m).find(|&i| (t + 1..n).any(|j| s[i][j] % s[t][t] != 0)) {
+                Some(i) => {
+                    sub_row(&mut s, t, i, -1)?;
+                    sub_row(&mut u, t, i, -1)?;
+                }
+                None => break,
+            }
+        }
+
+        if s[t][t] < 0 {
+            neg_row(&mut s, t)?;
+            neg_row(&mut u, t)?;
+        }
+    }
+
+    Ok((u, s, v))
+}
+
+impl Matrix {
+    /// Returns `true` if all elements of the matrix are integers.
+    pub fn is_integer(&self) -> bool {
+        to_rows(self).is_ok()
+    }
+
+    /// Calculate the row-style Hermite normal form of this integer matrix, return (H, U) such that `U A = H`, with U unimodular.
+    pub fn hermite_normal_form(&self) -> (Self, Self) {
+        detail::unwrap(self.try_hermite_normal_form())
+    }
+
+    /// Calculate the row-style Hermite normal form of this integer matrix, return (H, U) such that `U A = H`, with U unimodular,
+    /// or an error if an element is not an integer or an intermediate value overflows.
+    ///
+    /// H is in row echelon form, its pivots are positive, and the elements above each pivot are in `0..pivot`.
+    /// Only integer row operations are used: swaps, negations, and adding integer multiples of a row to another.
+    pub fn try_hermite_normal_form(&self) -> Result<(Self, Self), MatrixError> {
+        let (m, n) = self.shape();
+        let mut h = to_rows(self)?;
+        let mut u = identity(m);
+
+        let mut r = 0;
+        for c in 0..n {
+            if r == m {
+                break;
+            }
+
+            // Euclidean algorithm on the column: move the smallest non-zero element to row r and reduce the rest by it
+            while let Some(p) = (r..m).filter(|&i| h[i][c] != 0).min_by_key(|&i| h[i][c].unsigned_abs()) {
+                h.swap(r, p);
+                u.swap(r, p);
+                if (r + 1..m).all(|i| h[i][c] == 0) {
+                    break;
+                }
+                for i in r + 1..m {
+                    let q = div(h[i][c], h[r][c])?;
+                    sub_row(&mut h, i, r, q)?;
+                    sub_row(&mut u, i, r, q)?;
+                }
+            }
+            if h[r][c] == 0 {
+                continue;
+            }
+
+            // make the pivot positive and reduce the elements above it
+            if h[r][c] < 0 {
+                neg_row(&mut h, r)?;
+                neg_row(&mut u, r)?;
+            }
+            for i in 0..r {
+                let q = div(h[i][c], h[r][c])?;
+                sub_row(&mut h, i, r, q)?;
+                sub_row(&mut u, i, r, q)?;
+            }
+            r += 1;
+        }
+
+        Ok((to_matrix(h, n), to_matrix(u, m)))
+    }
+
+    /// Calculate the Smith normal form of this integer matrix, return (U, S, V) such that `U A V = S`, with U and V unimodular.
+    pub fn smith_normal_form(&self) -> (Self, Self, Self) {
+        detail::unwrap(self.try_smith_normal_form())
+    }
+
+    /// Calculate the Smith normal form of this integer matrix, return (U, S, V) such that `U A V = S`, with U and V unimodular,
+    /// or an error if an element is not an integer or an intermediate value overflows.
+    ///
+    /// S is diagonal, its diagonal elements are the invariant factors `d_1 | d_2 | ... | d_r` followed by zeros, all non-negative.
+    pub fn try_smith_normal_form(&self) -> Result<(Self, Self, Self), MatrixError> {
+        let (m, n) = self.shape();
+        let (u, s, v) = smith(to_rows(self)?, n)?;
+        Ok((to_matrix(u, m), to_matrix(s, n), to_matrix(v, n)))
+    }
+
+    /// Solve the system of linear equations `A x = b` over the integers, for an integer matrix.
+    pub fn solve_integer(&self, b: &Vector) -> Solution {
+        detail::unwrap(self.try_solve_integer(b))
+    }
+
+    /// Solve the system of linear equations `A x = b` over the integers, for an integer matrix,
+    /// or an error if the dimensions mismatch, an element of the matrix is not an integer, or an intermediate value overflows.
+    ///
+    /// The general integer solution is the particular solution plus any integer linear combination of the basis,
+    /// which is a basis of the lattice of the integer solutions of `A x = 0`.
+    ///
+    /// With `U A V = S`, the system becomes `S y = U b` with `x = V y`: it is solvable iff each invariant factor divides
+    /// the corresponding element of `U b` and the rest of `U b` is zero, and the free variables of y give the basis.
+    pub fn try_solve_integer(&self, b: &Vector) -> Result<Solution, MatrixError> {
+        detail::check_size(self.row_size(), b.size())?;
+
+        let (m, n) = self.shape();
+        let (u, s, v) = smith(to_rows(self)?, n)?;
+
+        // an integer x gives an integer A x
+        if (0..m).any(|i| b[i].denominator() != 1) {
+            return Ok(Solution::NoSolution);
+        }
+        let mut c = vec![0i128; m];
+        for (i, ci) in c.iter_mut().enumerate() {
+            for (j, bj) in b.elements.iter().enumerate() {
+                *ci = u[i][j].checked_mul(bj.numerator()).and_then(|e| ci.checked_add(e)).ok_or(MatrixError::Overflow)?;
+            }
+        }
+
+        let r = (0..m.min(n)).take_while(|&i| s[i][i] != 0).count();
+        if (0..r).any(|i| c[i] % s[i][i] != 0) || c[r..].iter().any(|&e| e != 0) {
+            return Ok(Solution::NoSolution);
+        }
+
+        let mut x = vec![0i128; n];
+        for (k, xk) in x.iter_mut().enumerate() {
+            for i in 0..r {
+                *xk = v[k][i].checked_mul(c[i] / s[i][i]).and_then(|e| xk.checked_add(e)).ok_or(MatrixError::Overflow)?;
+            }
+        }
+        let particular = Vector::from(x.into_iter().map(Fraction::from).collect::<Vec<_>>());
+
+        Ok(if r == n {
+            Solution::Unique(particular)
+        } else {
+            let basis = (r..n).map(|j| Vector::from((0..n).map(|k| Fraction::from(v[k][j])).collect::<Vec<_>>())).collect();
+            Solution::Infinite { particular, basis }
+        })
+    }
+}
@@ -0,0 +1,116 @@
+use pyinrs::Fraction;
+
+use crate::{detail, Matrix, MatrixError, Vector};
+
+/// A Jordan block: `size` x `size` with the eigenvalue on the diagonal and 1 on the superdiagonal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JordanBlock {
+    /// The eigenvalue on the diagonal.
+    pub eigenvalue: Fraction,
+
+    /// The order of the block.
+    pub size: usize,
+}
+
+/// Jordan normal form `A = P J P^-1` of a matrix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Jordan {
+    /// The transformation matrix, its columns are chains of generalized eigenvectors.
+    pub p: Matrix,
+
+    /// The Jordan matrix, block diagonal.
+    pub j: Matrix,
+
+    /// The blocks along the diagonal of J: eigenvalues in increasing order, and for each eigenvalue, sizes in decreasing order.
+    pub blocks: Vec<JordanBlock>,
+}
+
+impl Jordan {
+    /// Return the sizes of the blocks of the eigenvalue in decreasing order, empty if it is not an eigenvalue.
+    pub fn block_sizes(&self, eigenvalue: Fraction) -> Vec<usize> {
+        self.blocks.iter().filter(|b| b.eigenvalue == eigenvalue).map(|b| b.size).collect()
+    }
+}
+
+// Return N^k v.
+fn apply(n: &Matrix, v: &Vector, k: usize) -> Vector {
+    (0..k).fold(v.clone(), |v, _| n.apply(&v))
+}
+
+// Returns `true` if the vectors are linearly independent.
+fn independent(vectors: &[Vector]) -> bool {
+    Matrix { rows: vectors.to_vec() }.rank() == vectors.len()
+}
+
+impl Matrix {
+    /// Calculate the Jordan normal form `A = P J P^-1` of this matrix.
+    ///
+    /// Return `None` if the matrix has a non-rational eigenvalue.
+    pub fn jordan_form(&self) -> Option<Jordan> {
+        match self.try_jordan_form() {
+            Ok(jordan) => Some(jordan),
+            Err(MatrixError::NonRationalEigenvalue) => None,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Calculate the Jordan normal form `A = P J P^-1` of this matrix, or an error if it is not square or has a non-rational eigenvalue.
+    pub fn try_jordan_form(&self) -> Result<Jordan, MatrixError> {
+        detail::check_square(self)?;
+
+        let n = self.row_size();
+        let eigenvalues = self.try_rational_eigenvalues()?;
+        if eigenvalues.iter().map(|&(_, m)| m).sum::<usize>() != n {
+            return Err(MatrixError::NonRationalEigenvalue);
+        }
+
+        let (mut columns, mut blocks) = (Vec::with_capacity(n), Vec::new());
+        for (lambda, m) in eigenvalues {
+            // bases of ker(N^k) for k = 0, 1, ..., until the dimension reaches the algebraic multiplicity
+            let nil = self - Self::identity(n) * lambda;
+            let mut kernels = vec![Vec::new()];
+            let mut power = Self::identity(n);
+            while kernels.last().unwrap().len() < m {
+                power = power * &nil;
+                kernels.push(power.null_space());
+            }
+
+            // the tops of the chains with their lengths, from the longest chains down:
+            // at level k, the vectors of the longer chains are kept, and extended to a basis of ker(N^k) modulo ker(N^(k-1))
+            let mut tops: Vec<(Vector, usize)> = Vec::new();
+            for k in (1..kernels.len()).rev() {
+                let mut basis = kernels[k - 1].clone();
+                basis.extend(tops.iter().map(|(v, len)| apply(&nil, v, len - k)));
+                for u in &kernels[k] {
+                    basis.push(u.clone());
+                    if independent(&basis) {
+                        tops.push((u.clone(), k));
+                    } else {
+                        basis.pop();
+                    }
+                }
+            }
+
+            // each chain N^(len-1) v, ..., N v, v gives a block
+            for (v, len) in tops {
+                columns.extend((0..len).rev().map(|i| apply(&nil, &v, i)));
+                blocks.push(JordanBlock { eigenvalue: lambda, size: len });
+            }
+        }
+
+        let p = Self { rows: columns }.transpose();
+        let mut j = Self::zeros(n, n);
+        let mut offset = 0;
+        for block in &blocks {
+            for i in offset..offset + block.size {
+                j[i][i] = block.eigenvalue;
+                if i + 1 < offset + block.size {
+                    j[i][i + 1] = 1.into();
+                }
+            }
+            offset += block.size;
+        }
+
+        Ok(Jordan { p, j, blocks })
+    }
+}
@@ -0,0 +1,86 @@
+use pyinrs::Fraction;
+
+use crate::{detail, Matrix, MatrixError};
+
+// Return the nearest integer, halves are rounded up.
+fn round(x: Fraction) -> Fraction {
+    let x = x + Fraction::from((1, 2));
+    Fraction::from(x.numerator().div_euclid(x.denominator()))
+}
+
+impl Matrix {
+    /// LLL lattice basis reduction of the rows with the parameter δ, return (B', U) such that `U B = B'`, with U unimodular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows are linearly dependent, or δ is not in `(1/4, 1]`.
+    pub fn lll(&self, delta: Fraction) -> (Self, Self) {
+        detail::unwrap(self.try_lll(delta))
+    }
+
+    /// LLL lattice basis reduction of the rows with the parameter δ, return (B', U) such that `U B = B'`, with U unimodular,
+    /// or an error if the rows are linearly dependent or δ is not in `(1/4, 1]`.
+    ///
+    /// The reduced basis is size-reduced, `|μ_ij| <= 1/2`, and satisfies the Lovász condition
+    /// `|b*_k|^2 >= (δ - μ_k,k-1^2) |b*_k-1|^2`. A larger δ gives a better reduced basis with more steps, 3/4 is the usual choice.
+    pub fn try_lll(&self, delta: Fraction) -> Result<(Self, Self), MatrixError> {
+        if delta <= Fraction::from((1, 4)) || delta > 1.into() {
+            return Err(MatrixError::InvalidDelta { delta });
+        }
+
+        let n = self.row_size();
+        let mut b = self.clone();
+        let mut u = Self::identity(n);
+        let (orthogonal, mut mu) = b.gram_schmidt();
+        let mut norms = orthogonal.rows.iter().map(|v| v * v).collect::<Vec<_>>();
+        if norms.iter().any(|&norm| norm == 0.into()) {
+            return Err(MatrixError::LinearlyDependent);
+        }
+
+        let mut k = 1;
+        while k < n {
+            // size reduction of b_k against b_(k-1), ..., b_0, B* is unchanged
+            for j in (0..k).rev() {
+                if mu[k][j].abs() > Fraction::from((1, 2)) {
+                    let q = round(mu[k][j]);
+                    b[k] = &b[k] - &b[j] * q;
+                    u[k] = &u[k] - &u[j] * q;
+                    for l in 0..j {
+                        let e = q * mu[j][l];
+                        mu[k][l] -= e;
+                    }
+                    mu[k][j] -= q;
+                }
+            }
+
+            // Lovász condition, otherwise swap b_k and b_(k-1) and step back
+            let m = mu[k][k - 1];
+            if norms[k] >= (delta - m * m) * norms[k - 1] {
+                k += 1;
+                continue;
+            }
+
+            b.rows.swap(k, k - 1);
+            u.rows.swap(k, k - 1);
+
+            // only b*_k-1 and b*_k change, update their norms and the coefficients along them
+            let norm = norms[k] + m * m * norms[k - 1];
+            mu[k][k - 1] = m * norms[k - 1] / norm;
+            norms[k] = norms[k - 1] * norms[k] / norm;
+            norms[k - 1] = norm;
+
+            let (upper, lower) = mu.rows.split_at_mut(k);
+            upper[k - 1].elements[..k - 1].swap_with_slice(&mut lower[0].elements[..k - 1]);
+            let m2 = mu[k][k - 1];
+            for row in mu.rows.iter_mut().skip(k + 1) {
+                let t = row[k];
+                row[k] = row[k - 1] - m * t;
+                row[k - 1] = t + m2 * row[k];
+            }
+
+            k = (k - 1).max(1);
+        }
+
+        Ok((b, u))
+    }
+}
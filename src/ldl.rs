@@ -0,0 +1,183 @@
+use pyinrs::Fraction;
+
+use crate::{detail, Field, Matrix, MatrixError};
+
+/// LDL^T decomposition `P A P^T = L D L^T` of a symmetric matrix, with symmetric pivoting.
+///
+/// P is a permutation matrix, L is unit lower triangular, and D is block diagonal with 1 x 1 and symmetric 2 x 2 blocks.
+/// A 2 x 2 block is only used when the remaining diagonal is all zero, e.g. for `[[0, 1], [1, 0]]`, so D is diagonal
+/// whenever possible. All elements stay in the field, no square roots are taken.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ldl<T = Fraction> {
+    /// The symmetric permutation: the i-th row and column of `P A P^T` are the `perm[i]`-th row and column of A.
+    pub perm: Vec<usize>,
+
+    /// The unit lower triangular factor.
+    pub l: Matrix<T>,
+
+    /// The block diagonal factor.
+    pub d: Matrix<T>,
+
+    /// The orders of the blocks along the diagonal of D, each 1 or 2.
+    pub blocks: Vec<usize>,
+}
+
+impl<T: Field> Ldl<T> {
+    /// Return the permutation matrix P.
+    pub fn p(&self) -> Matrix<T> {
+        let n = self.perm.len();
+        let mut p = Matrix::filled(n, n, self.d.zero_like());
+        for (i, &j) in self.perm.iter().enumerate() {
+            p[i][j] = self.d.one_like();
+        }
+        p
+    }
+}
+
+impl Ldl {
+    /// Return the inertia (positive, negative, zero) of the original matrix, the numbers of its eigenvalues of each sign.
+    ///
+    /// By Sylvester's law of inertia it is the inertia of D, and a 2 x 2 block has eigenvalues of opposite signs
+    /// if its determinant is negative, otherwise both have the sign of its trace.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        let (mut positive, mut negative, mut zero) = (0, 0, 0);
+        let mut k = 0;
+        for &size in &self.blocks {
+            if size == 1 {
+                match self.d[k][k] {
+                    x if x > 0.into() => positive += 1,
+                    x if x < 0.into() => negative += 1,
+                    _ => zero += 1,
+                }
+            } else {
+                let det = self.d[k][k] * self.d[k + 1][k + 1] - self.d[k][k + 1] * self.d[k + 1][k];
+                let trace = self.d[k][k] + self.d[k + 1][k + 1];
+                match det {
+                    x if x < 0.into() => (positive, negative) = (positive + 1, negative + 1),
+                    x if x > 0.into() && trace > 0.into() => positive += 2,
+                    x if x > 0.into() => negative += 2,
+                    _ if trace > 0.into() => (positive, zero) = (positive + 1, zero + 1),
+                    _ if trace < 0.into() => (negative, zero) = (negative + 1, zero + 1),
+                    _ => zero += 2,
+                }
+            }
+            k += size;
+        }
+        (positive, negative, zero)
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// LDL^T decomposition with symmetric pivoting, return the factorization `P A P^T = L D L^T` of this symmetric matrix.
+    pub fn ldl(&self) -> Ldl<T> {
+        detail::unwrap(self.try_ldl())
+    }
+
+    /// LDL^T decomposition with symmetric pivoting, return the factorization `P A P^T = L D L^T` of this symmetric matrix,
+    /// or an error if it is not square or not symmetric.
+    ///
+    /// The pivot is the first non-zero element on the remaining diagonal, otherwise the first non-zero off-diagonal element
+    /// `a_ij` gives the 2 x 2 pivot `[[a_ii, a_ij], [a_ji, a_jj]]`, which has the determinant `-a_ij^2` then.
+    pub fn try_ldl(&self) -> Result<Ldl<T>, MatrixError> {
+        detail::check_square(self)?;
+        if !self.is_symmetric() {
+            return Err(MatrixError::NotSymmetric);
+        }
+
+        let n = self.row_size();
+        let mut perm = (0..n).collect::<Vec<_>>();
+        let mut l = Self::scalar(n, self.one_like());
+        let mut d = Self::filled(n, n, self.zero_like());
+        let mut blocks = Vec::new();
+        let mut s = self.clone();
+
+        // move the i-th row and column to the k-th, including the computed part of L
+        let swap = |s: &mut Self, l: &mut Self, perm: &mut Vec<usize>, k: usize, i: usize| {
+            s.rows.swap(k, i);
+            s.rows.iter_mut().for_each(|row| row.elements.swap(k, i));
+            perm.swap(k, i);
+            for j in 0..k {
+                let t = l[k][j].clone();
+                l[k][j] = l[i][j].clone();
+                l[i][j] = t;
+            }
+        };
+
+        let mut k = 0;
+        while k < n {
+            if let Some(i) = (k..n).find(|&i| !s[i][i].is_zero()) {
+                // 1 x 1 pivot: eliminate the column below it, and the Schur complement stays symmetric
+                swap(&mut s, &mut l, &mut perm, k, i);
+                let pivot = s[k][k].clone();
+                for i in k + 1..n {
+                    l[i][k] = s[i][k].clone() / pivot.clone();
+                    for j in k + 1..n {
+                        let e = l[i][k].clone() * s[k][j].clone();
+                        s[i][j] -= e;
+                    }
+                }
+                d[k][k] = pivot;
+                blocks.push(1);
+                k += 1;
+            } else if let Some((i, j)) = (k..n).flat_map(|i| (i + 1..n).map(move |j| (i, j))).find(|&(i, j)| !s[i][j].is_zero()) {
+                // 2 x 2 pivot E = [[0, b], [b, 0]], E^-1 = [[0, 1/b], [1/b, 0]], the rows below get (s_i,k+1 / b, s_ik / b)
+                swap(&mut s, &mut l, &mut perm, k, i);
+                swap(&mut s, &mut l, &mut perm, k + 1, j);
+                let b = s[k][k + 1].clone();
+                for i in k + 2..n {
+                    l[i][k] = s[i][k + 1].clone() / b.clone();
+                    l[i][k + 1] = s[i][k].clone() / b.clone();
+                    for j in k + 2..n {
+                        let e = l[i][k].clone() * s[k][j].clone() + l[i][k + 1].clone() * s[k + 1][j].clone();
+                        s[i][j] -= e;
+                    }
+                }
+                d[k][k + 1] = b.clone();
+                d[k + 1][k] = b;
+                blocks.push(2);
+                k += 2;
+            } else {
+                // the rest is zero
+                blocks.extend(std::iter::repeat_n(1, n - k));
+                break;
+            }
+        }
+
+        Ok(Ldl { perm, l, d, blocks })
+    }
+
+    /// LDL^T decomposition with symmetric pivoting, return (P, L, D) such that `P A P^T = L D L^T`.
+    pub fn ldl_decomposition(&self) -> (Self, Self, Self) {
+        let ldl = self.ldl();
+        (ldl.p(), ldl.l, ldl.d)
+    }
+}
+
+impl Matrix {
+    /// Return the inertia (positive, negative, zero) of this symmetric matrix, the numbers of its eigenvalues of each sign.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        detail::unwrap(self.try_inertia())
+    }
+
+    /// Return the inertia (positive, negative, zero) of this symmetric matrix, or an error if it is not square or not symmetric.
+    ///
+    /// The eigenvalues are not calculated: by Sylvester's law of inertia, it is the inertia of D in the LDL^T decomposition.
+    pub fn try_inertia(&self) -> Result<(usize, usize, usize), MatrixError> {
+        Ok(self.try_ldl()?.inertia())
+    }
+
+    /// Returns `true` if the matrix is symmetric and positive definite, i.e. `x^T A x > 0` for all `x != 0`.
+    pub fn is_positive_definite(&self) -> bool {
+        self.is_symmetric() && self.inertia().0 == self.row_size()
+    }
+
+    /// Returns `true` if the matrix is symmetric and positive semidefinite, i.e. `x^T A x >= 0` for all x.
+    pub fn is_positive_semidefinite(&self) -> bool {
+        self.is_symmetric() && self.inertia().1 == 0
+    }
+
+    /// Returns `true` if the matrix is symmetric and negative definite, i.e. `x^T A x < 0` for all `x != 0`.
+    pub fn is_negative_definite(&self) -> bool {
+        self.is_symmetric() && self.inertia().1 == self.row_size()
+    }
+}
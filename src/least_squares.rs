@@ -0,0 +1,66 @@
+use pyinrs::Fraction;
+
+use crate::{detail, Matrix, MatrixError, Solution, Vector};
+
+impl Matrix {
+    /// Solve `A x = b` in the least squares sense, return the minimum-norm solution x and the residual `|A x - b|^2`.
+    pub fn least_squares(&self, b: &Vector) -> (Vector, Fraction) {
+        detail::unwrap(self.try_least_squares(b))
+    }
+
+    /// Solve `A x = b` in the least squares sense, return the minimum-norm solution x and the residual `|A x - b|^2`,
+    /// or an error if the dimensions mismatch.
+    ///
+    /// Solve the normal equations `A^T A x = A^T b`, which are always consistent. If A is rank deficient, the solutions are
+    /// a particular one plus `ker(A)`, and the minimum-norm one is orthogonal to `ker(A)`.
+    pub fn try_least_squares(&self, b: &Vector) -> Result<(Vector, Fraction), MatrixError> {
+        detail::check_size(self.row_size(), b.size())?;
+
+        let t = self.transpose();
+        let x = match (&t * self).solve(&t.apply(b)) {
+            Solution::Unique(x) => x,
+            Solution::Infinite { particular, basis } => Vector::gram_schmidt(&basis)
+                .iter()
+                .fold(particular.clone(), |x, q| &x - &(q * (&particular * q / (q * q)))),
+            Solution::NoSolution => unreachable!("the normal equations are consistent"),
+        };
+        let residual = self.residual(&x, b);
+        Ok((x, residual))
+    }
+
+    /// Calculate the residual `|A x - b|^2` exactly.
+    pub fn residual(&self, x: &Vector, b: &Vector) -> Fraction {
+        detail::unwrap(self.try_residual(x, b))
+    }
+
+    /// Calculate the residual `|A x - b|^2` exactly, or an error if the dimensions mismatch.
+    pub fn try_residual(&self, x: &Vector, b: &Vector) -> Result<Fraction, MatrixError> {
+        detail::check_size(self.col_size(), x.size())?;
+        detail::check_size(self.row_size(), b.size())?;
+
+        let r = &self.apply(x) - b;
+        Ok(&r * &r)
+    }
+
+    /// Calculate the Moore-Penrose pseudoinverse of this matrix exactly.
+    ///
+    /// With the full-rank factorization `A = C F`, where C is the pivot columns of A and F is the non-zero rows of its
+    /// reduced row echelon form, the pseudoinverse is `F^T (F F^T)^-1 (C^T C)^-1 C^T`.
+    pub fn pinv(&self) -> Self {
+        let (m, n) = self.shape();
+        let rref = self.rref();
+        let r = rref.rank();
+        if r == 0 {
+            return Self::zeros(n, m);
+        }
+
+        let c = Self::from_fn(m, r, |i, k| self[i][rref.pivots[k]]);
+        let f = Self::from_fn(r, n, |k, j| rref.matrix[k][j]);
+        let (ct, ft) = (c.transpose(), f.transpose());
+
+        // C^T C and F F^T are r x r of rank r, so they are invertible
+        let left = (&f * &ft).inv().unwrap();
+        let right = (&ct * &c).inv().unwrap();
+        ft * left * right * ct
+    }
+}
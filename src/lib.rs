@@ -1,10 +1,47 @@
 #![doc = include_str!("../readme.md")]
 
+#[cfg(feature = "big-fraction")]
+mod big_fraction;
 mod detail;
-
+mod echelon;
+mod eigen;
+mod error;
+mod field;
+mod frobenius;
+mod gf;
+mod integer;
+mod jordan;
+mod lattice;
+mod ldl;
+mod least_squares;
 mod matrix;
+mod min_poly;
+mod modular;
+mod orthogonal;
+mod plu;
+mod polynomial;
+mod projection;
+mod quadratic;
+mod solve;
+mod space;
 mod vector;
 
+#[cfg(feature = "big-fraction")]
+pub use big_fraction::BigFraction;
+pub use echelon::Rref;
+pub use error::MatrixError;
+pub use field::Field;
+pub use frobenius::Frobenius;
+pub use gf::{Gf, GfDyn};
+pub use jordan::{Jordan, JordanBlock};
+pub use ldl::Ldl;
 pub use matrix::Matrix;
+pub use plu::Plu;
+pub use polynomial::Polynomial;
+pub use projection::{projector, try_projector};
 pub use pyinrs::Fraction;
+#[cfg(feature = "big-fraction")]
+pub use pyinrs::Int;
+pub use quadratic::{Definiteness, QuadraticForm};
+pub use solve::Solution;
 pub use vector::Vector;
@@ -1,16 +1,19 @@
 use std::{
     fmt::Display,
-    ops::{Index, IndexMut},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::{detail, Vector};
+use crate::{
+    detail::{self, forward_binop},
+    Field, MatrixError, Vector,
+};
 
 use pyinrs::Fraction;
 
-/// Matrix with fractions as elements.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
-pub struct Matrix {
-    rows: Vec<Vector>,
+/// Matrix with elements of a field, fractions by default.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T = Fraction> {
+    pub(crate) rows: Vec<Vector<T>>,
 }
 
 impl Matrix {
@@ -21,11 +24,7 @@ impl Matrix {
 
     /// Create a row x col matrix with all identical elements.
     pub fn create(row: usize, col: usize, value: Fraction) -> Self {
-        let mut rows = Vec::with_capacity(row);
-        for _ in 0..row {
-            rows.push(Vector::create(col, value));
-        }
-        Self { rows }
+        Self::filled(row, col, value)
     }
 
     /// Create a row x col matrix with all 0 elements.
@@ -40,9 +39,30 @@ impl Matrix {
 
     /// Generate an n-order identity matrix.
     pub fn identity(n: usize) -> Self {
-        let mut m = Self::zeros(n, n);
+        Self::scalar(n, 1.into())
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// Create a row x col matrix with all identical elements, of any element type.
+    pub fn filled(row: usize, col: usize, value: T) -> Self {
+        Self {
+            rows: vec![Vector::filled(col, value); row],
+        }
+    }
+
+    /// Create a row x col matrix whose element at (i, j) is `f(i, j)`, of any element type.
+    pub fn from_fn(row: usize, col: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Self {
+            rows: (0..row).map(|i| Vector::from_fn(col, |j| f(i, j))).collect(),
+        }
+    }
+
+    /// Generate an n-order scalar matrix with `value` on the diagonal, of any element type.
+    pub fn scalar(n: usize, value: T) -> Self {
+        let mut m = Self::filled(n, n, value.zero_like());
         for i in 0..n {
-            m[i][i] = 1.into();
+            m[i][i] = value.clone();
         }
         m
     }
@@ -61,6 +81,11 @@ impl Matrix {
         }
     }
 
+    /// Return the shape (rows, cols) of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.row_size(), self.col_size())
+    }
+
     /// Returns `true` if the matrix contains no elements.
     pub fn is_empty(&self) -> bool {
         self.rows.is_empty()
@@ -91,7 +116,7 @@ impl Matrix {
 
         for r in 1..self.row_size() {
             for c in 0..r {
-                if self[r][c] != 0.into() {
+                if !self[r][c].is_zero() {
                     return false;
                 }
             }
@@ -108,7 +133,7 @@ impl Matrix {
 
         for c in 1..self.col_size() {
             for r in 0..c {
-                if self[r][c] != 0.into() {
+                if !self[r][c].is_zero() {
                     return false;
                 }
             }
@@ -123,27 +148,25 @@ impl Matrix {
     }
 
     /// Calculate the trace of the matrix.
-    pub fn trace(&self) -> Fraction {
-        detail::check_square(self);
+    pub fn trace(&self) -> T {
+        detail::unwrap(self.try_trace())
+    }
+
+    /// Calculate the trace of the matrix, or an error if it is not square.
+    pub fn try_trace(&self) -> Result<T, MatrixError> {
+        detail::check_square(self)?;
 
-        let mut tr = Fraction::new();
+        let mut tr = self.zero_like();
         for i in 0..self.row_size() {
-            tr += self[i][i];
+            tr += self[i][i].clone();
         }
 
-        tr
+        Ok(tr)
     }
 
     /// Returns the transpose of the matrix.
     pub fn transpose(&self) -> Self {
-        let mut result = Self::zeros(self.col_size(), self.row_size());
-
-        for i in 0..self.row_size() {
-            for j in 0..self.col_size() {
-                result[j][i] = self[i][j];
-            }
-        }
-        result
+        Self::from_fn(self.col_size(), self.row_size(), |i, j| self[j][i].clone())
     }
 
     /// Transform this matrix to general row echelon form.
@@ -153,12 +176,12 @@ impl Matrix {
         // Gaussian elimination
         for i in 0..m.row_size() {
             let mut j: usize = 0;
-            while j < m.col_size() && m.rows[i][j] == 0.into() {
+            while j < m.col_size() && m.rows[i][j].is_zero() {
                 j += 1;
             }
             for k in i + 1..m.row_size() {
-                if j < m.col_size() && m.rows[i][j] != 0.into() {
-                    m.e_row_sum(k, i, -m.rows[k][j] / m.rows[i][j]);
+                if j < m.col_size() && !m.rows[i][j].is_zero() {
+                    m.e_row_sum(k, i, -m.rows[k][j].clone() / m.rows[i][j].clone());
                 }
             }
         }
@@ -171,57 +194,27 @@ impl Matrix {
 
     /// Transform this matrix to reduced row echelon form.
     pub fn row_canonical_form(&self) -> Self {
-        let mut m = self.row_echelon_form();
-
-        let n = usize::min(m.row_size(), m.col_size());
-
-        // eliminate elements above the pivot
-        for c in 0..n {
-            for r in 0..c {
-                if m[c][c] != 0.into() {
-                    m.e_row_sum(r, c, -(m[r][c] / m[c][c]));
-                }
-            }
-        }
-
-        // make pivot equals 1
-        let mut i = 0;
-        while i < n && m[i][i] != 0.into() {
-            m.e_scalar_multiplication(i, Fraction::from(1) / m[i][i]);
-            i += 1;
-        }
-
-        m
+        self.rref().matrix
     }
 
     /// Calculate the determinant of this matrix.
-    pub fn det(&self) -> Fraction {
-        detail::check_square(self);
+    pub fn det(&self) -> T {
+        detail::unwrap(self.try_det())
+    }
 
-        let n = self.row_size();
-        let mut a = self.clone();
+    /// Calculate the determinant of this matrix, or an error if it is not square.
+    pub fn try_det(&self) -> Result<T, MatrixError> {
+        detail::check_square(self)?;
 
-        let mut det = Fraction::from(1);
-        for i in 0..n {
-            let mut pivot = i;
-            for j in i + 1..n {
-                if a[j][i].abs() > a[pivot][i].abs() {
-                    pivot = j;
-                }
-            }
-            if pivot != i {
-                a.e_row_swap(i, pivot);
-                det = -det;
-            }
-            if a[i][i] == 0.into() {
-                return Fraction::new();
-            }
-            det *= a[i][i];
-            for j in i + 1..n {
-                a.e_row_sum(j, i, -a[j][i] / a[i][i]);
-            }
+        // use Bareiss algorithm, the last pivot is the determinant up to sign
+        let n = self.row_size();
+        let (m, pivots, odd) = self.bareiss();
+        if pivots.len() < n {
+            return Ok(self.zero_like());
         }
-        det
+
+        let det = if n == 0 { T::one() } else { m[n - 1][n - 1].clone() };
+        Ok(if odd { -det } else { det })
     }
 
     /// Return the matrix that removed the i-th row and j-th column, 0 <= i, j < n.
@@ -240,13 +233,7 @@ impl Matrix {
 
     /// Return the minor matrix.
     pub fn minor(&self) -> Self {
-        let mut m = Self::zeros(self.row_size(), self.col_size());
-        for r in 0..m.row_size() {
-            for c in 0..m.col_size() {
-                m[r][c] = self.submatrix(r, c).det();
-            }
-        }
-        m
+        Self::from_fn(self.row_size(), self.col_size(), |r, c| self.submatrix(r, c).det())
     }
 
     /// Return the cofactor matrix.
@@ -256,7 +243,7 @@ impl Matrix {
             for c in 0..self.col_size() {
                 // a11 -> a00, r+c parity unchanged
                 if (r + c) & 1 == 1 {
-                    m[r][c] = -m[r][c];
+                    m[r][c] = -m[r][c].clone();
                 }
             }
         }
@@ -270,83 +257,169 @@ impl Matrix {
 
     /// Calculate the inverse of this matrix.
     pub fn inv(&self) -> Option<Self> {
-        detail::check_square(self);
+        match self.try_inv() {
+            Ok(inv) => Some(inv),
+            Err(MatrixError::Singular) => None,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Calculate the inverse of this matrix, or an error if it is not square or singular.
+    pub fn try_inv(&self) -> Result<Self, MatrixError> {
+        detail::check_square(self)?;
 
         // inverse of empty matrix is empty matrix
         if self.is_empty() {
-            return Some(Matrix::new());
+            return Ok(Self::default());
         }
 
         // generate augmented matrix [A:E] and transform [A:E] to reduced row echelon form and split
         let n = self.row_size();
-        let rref = self.clone().expand_col(Self::identity(n)).row_canonical_form().split_col(n);
+        let rref = self.clone().expand_col(Self::scalar(n, self.one_like())).row_canonical_form().split_col(n);
 
         // now, the original E is the inverse of A if rank = n
         if !rref.0[n - 1].is_zero() {
-            Some(rref.1)
+            Ok(rref.1)
         } else {
-            None
+            Err(MatrixError::Singular)
         }
     }
 
+    /// Calculate the integer power `A^exp` of this matrix, `A^0 = I` and `A^-n = (A^-1)^n`.
+    pub fn pow(&self, exp: i64) -> Self {
+        detail::unwrap(self.try_pow(exp))
+    }
+
+    /// Calculate the integer power `A^exp` of this matrix, or an error if it is not square, or singular with a negative `exp`.
+    ///
+    /// Use binary exponentiation, O(log |exp|) matrix multiplications.
+    pub fn try_pow(&self, exp: i64) -> Result<Self, MatrixError> {
+        detail::check_square(self)?;
+
+        let mut base = if exp < 0 { self.try_inv()? } else { self.clone() };
+        let mut result = Self::scalar(self.row_size(), self.one_like());
+        let mut exp = exp.unsigned_abs();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Calculate `A^exp v` without forming `A^exp`.
+    pub fn pow_apply(&self, exp: i64, v: &Vector<T>) -> Vector<T> {
+        detail::unwrap(self.try_pow_apply(exp, v))
+    }
+
+    /// Calculate `A^exp v` without forming `A^exp`,
+    /// or an error if the matrix is not square, or singular with a negative `exp`, or the dimensions mismatch.
+    ///
+    /// While `|exp| <= n log2 |exp|` the vector is multiplied by A (or `A^-1`) |exp| times, which takes `n^2 |exp|` operations.
+    /// For larger `|exp|` the squares `A^(2^k)` are unavoidable to take only `n^3 log2 |exp|` operations, each one is applied
+    /// to the vector for the set bits of `|exp|`.
+    pub fn try_pow_apply(&self, exp: i64, v: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        detail::check_square(self)?;
+        detail::check_size(self.col_size(), v.size())?;
+
+        let mut base = if exp < 0 { self.try_inv()? } else { self.clone() };
+        let mut result = v.clone();
+        let mut exp = exp.unsigned_abs();
+        if exp <= self.row_size() as u64 * u64::from(u64::BITS - exp.leading_zeros()) {
+            for _ in 0..exp {
+                result = base.apply(&result);
+            }
+            return Ok(result);
+        }
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = base.apply(&result);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        Ok(result)
+    }
+
     /// Calculate the rank of this matrix.
     pub fn rank(&self) -> usize {
-        let zeros = self.row_echelon_form().rows.iter().filter(|row| row.is_zero()).count();
-        self.row_size() - zeros
+        self.bareiss().1.len()
     }
 
     /// LU decomposition, use Doolittle algorithm.
     pub fn lu_decomposition(&self) -> (Self, Self) {
-        detail::check_square(self);
+        detail::unwrap(self.try_lu_decomposition())
+    }
 
-        let n = self.row_size();
+    /// LU decomposition, use Doolittle algorithm, or an error if it is not square or a zero pivot is encountered.
+    pub fn try_lu_decomposition(&self) -> Result<(Self, Self), MatrixError> {
+        detail::check_square(self)?;
 
-        if self.is_upper() {
-            return (Matrix::zeros(n, n), self.clone());
-        } else if self.is_lower() {
-            return (self.clone(), Matrix::zeros(n, n));
-        }
+        let n = self.row_size();
 
-        let mut l = Self::identity(n);
-        let mut u = Self::zeros(n, n);
+        let mut l = Self::scalar(n, self.one_like());
+        let mut u = Self::filled(n, n, self.zero_like());
 
         for i in 0..n {
             for j in 0..(i + 1) {
-                let mut sum = Fraction::new();
+                let mut sum = self.zero_like();
                 for k in 0..j {
-                    sum += l[j][k] * u[k][i];
+                    sum += l[j][k].clone() * u[k][i].clone();
                 }
-                u[j][i] = self[j][i] - sum;
+                u[j][i] = self[j][i].clone() - sum;
             }
 
             for j in (i + 1)..n {
-                let mut sum = Fraction::new();
+                let mut sum = self.zero_like();
                 for k in 0..i {
-                    sum += l[j][k] * u[k][i];
+                    sum += l[j][k].clone() * u[k][i].clone();
+                }
+                // zero pivot is only allowed if the element is already eliminated, then any multiplier works
+                if u[i][i].is_zero() {
+                    if !(self[j][i].clone() - sum).is_zero() {
+                        return Err(MatrixError::ZeroPivot { index: i });
+                    }
+                    continue;
                 }
-                l[j][i] = (self[j][i] - sum) / u[i][i];
+                l[j][i] = (self[j][i].clone() - sum) / u[i][i].clone();
             }
         }
 
-        (l, u)
+        Ok((l, u))
     }
 
     /// Split this matrix by rows.
     pub fn split_row(&self, n: usize) -> (Self, Self) {
-        detail::check_bounds(n, 0, self.row_size());
+        detail::unwrap(self.try_split_row(n))
+    }
+
+    /// Split this matrix by rows, or an error if the index is out of range.
+    pub fn try_split_row(&self, n: usize) -> Result<(Self, Self), MatrixError> {
+        detail::check_bounds(n, 0, self.row_size())?;
 
-        let (mut first, mut second) = (Self::new(), Self::new());
+        let (mut first, mut second) = (Self::default(), Self::default());
         first.rows = self.rows[0..n].to_vec();
         second.rows = self.rows[n..].to_vec();
 
-        (first, second)
+        Ok((first, second))
     }
 
     /// Split this matrix by columns.
     pub fn split_col(&self, n: usize) -> (Self, Self) {
-        detail::check_bounds(n, 0, self.col_size());
+        detail::unwrap(self.try_split_col(n))
+    }
+
+    /// Split this matrix by columns, or an error if the index is out of range.
+    pub fn try_split_col(&self, n: usize) -> Result<(Self, Self), MatrixError> {
+        detail::check_bounds(n, 0, self.col_size())?;
 
-        let (mut first, mut second) = (Self::new(), Self::new());
+        let (mut first, mut second) = (Self::default(), Self::default());
         first.rows.resize(self.row_size(), Default::default());
         second.rows.resize(self.row_size(), Default::default());
         for r in 0..self.row_size() {
@@ -354,25 +427,112 @@ impl Matrix {
             second.rows[r].elements = self.rows[r].elements[n..].to_vec();
         }
 
-        (first, second)
+        Ok((first, second))
     }
 
     /// Expand this matrix by rows.
-    pub fn expand_row(&mut self, mut matrix: Self) -> &Self {
-        detail::check_size(self.col_size(), matrix.col_size());
+    pub fn expand_row(&mut self, matrix: Self) -> &Self {
+        detail::unwrap(self.try_expand_row(matrix))
+    }
+
+    /// Expand this matrix by rows, or an error if the number of columns mismatch.
+    pub fn try_expand_row(&mut self, mut matrix: Self) -> Result<&Self, MatrixError> {
+        if self.col_size() != matrix.col_size() {
+            return Err(MatrixError::ShapeMismatch {
+                lhs: self.shape(),
+                rhs: matrix.shape(),
+            });
+        }
 
         self.rows.append(&mut matrix.rows);
-        self
+        Ok(self)
     }
 
     /// Expand this matrix by columns.
-    pub fn expand_col(&mut self, mut matrix: Self) -> &Self {
-        detail::check_size(self.row_size(), matrix.row_size());
+    pub fn expand_col(&mut self, matrix: Self) -> &Self {
+        detail::unwrap(self.try_expand_col(matrix))
+    }
+
+    /// Expand this matrix by columns, or an error if the number of rows mismatch.
+    pub fn try_expand_col(&mut self, mut matrix: Self) -> Result<&Self, MatrixError> {
+        if self.row_size() != matrix.row_size() {
+            return Err(MatrixError::ShapeMismatch {
+                lhs: self.shape(),
+                rhs: matrix.shape(),
+            });
+        }
 
         for i in 0..self.row_size() {
             self.rows[i].elements.append(&mut matrix[i].elements);
         }
-        self
+        Ok(self)
+    }
+
+    /// Return the sum of two matrices, or an error if the shapes mismatch.
+    pub fn try_add(&self, that: &Self) -> Result<Self, MatrixError> {
+        detail::check_shape(self.shape(), that.shape())?;
+
+        let mut result = self.clone();
+        for r in 0..result.row_size() {
+            result[r] += &that[r];
+        }
+        Ok(result)
+    }
+
+    /// Return the difference of two matrices, or an error if the shapes mismatch.
+    pub fn try_sub(&self, that: &Self) -> Result<Self, MatrixError> {
+        detail::check_shape(self.shape(), that.shape())?;
+
+        let mut result = self.clone();
+        for r in 0..result.row_size() {
+            result[r] -= &that[r];
+        }
+        Ok(result)
+    }
+
+    /// Return the product of two matrices, or an error if the number of columns of `self` mismatch the number of rows of `that`.
+    pub fn try_mul(&self, that: &Self) -> Result<Self, MatrixError> {
+        if self.col_size() != that.row_size() {
+            return Err(MatrixError::ShapeMismatch {
+                lhs: self.shape(),
+                rhs: that.shape(),
+            });
+        }
+
+        let mut result = Self::filled(self.row_size(), that.col_size(), self.zero_like());
+        if self.col_size() == 0 {
+            return Ok(result);
+        }
+
+        let rt = that.transpose();
+        for r in 0..self.row_size() {
+            for c in 0..that.col_size() {
+                result[r][c] = &self[r] * &rt[c];
+            }
+        }
+        Ok(result)
+    }
+
+    // Return the product of this matrix and a column vector, the sizes must match.
+    pub(crate) fn apply(&self, v: &Vector<T>) -> Vector<T> {
+        Vector::from_fn(self.row_size(), |i| &self[i] * v)
+    }
+
+    // Return the additive identity of the same kind as the elements, or `T::zero()` if there are none.
+    pub(crate) fn zero_like(&self) -> T {
+        self.rows.iter().find_map(|row| row.elements.first()).map_or_else(T::zero, T::zero_like)
+    }
+
+    // Return the multiplicative identity of the same kind as the elements, or `T::one()` if there are none.
+    pub(crate) fn one_like(&self) -> T {
+        self.rows.iter().find_map(|row| row.elements.first()).map_or_else(T::one, T::one_like)
+    }
+
+    /// Return the matrix obtained by applying `f` to each element, possibly of another element type.
+    pub fn map<U: Field>(&self, mut f: impl FnMut(&T) -> U) -> Matrix<U> {
+        Matrix {
+            rows: self.rows.iter().map(|row| row.map(&mut f)).collect(),
+        }
     }
 
     /// Elementary Row Operations: Row Swap. (A[i] <=> A[j])
@@ -382,21 +542,39 @@ impl Matrix {
     }
 
     /// Elementary Row Operations: Scalar Multiplication. (A[i] *= k)
-    pub fn e_scalar_multiplication(&mut self, i: usize, k: Fraction) -> &Self {
+    pub fn e_scalar_multiplication(&mut self, i: usize, k: T) -> &Self {
         self.rows[i] *= k;
         self
     }
 
     /// Elementary Row Operations: Row Sum. (A[i] += A[j] * k)
-    pub fn e_row_sum(&mut self, i: usize, j: usize, k: Fraction) -> &Self {
+    pub fn e_row_sum(&mut self, i: usize, j: usize, k: T) -> &Self {
         let row = self[j].clone();
         self.rows[i] += row * k;
         self
     }
 }
 
-impl<const R: usize, const C: usize> From<[[Fraction; C]; R]> for Matrix {
-    fn from(value: [[Fraction; C]; R]) -> Self {
+impl<T: Field> Vector<T> {
+    /// Calculate `A^exp v` for this vector v without forming `A^exp`, see [`Matrix::pow_apply`].
+    pub fn apply_pow(&self, a: &Matrix<T>, exp: i64) -> Self {
+        detail::unwrap(self.try_apply_pow(a, exp))
+    }
+
+    /// Calculate `A^exp v` for this vector v without forming `A^exp`, or an error as [`Matrix::try_pow_apply`].
+    pub fn try_apply_pow(&self, a: &Matrix<T>, exp: i64) -> Result<Self, MatrixError> {
+        a.try_pow_apply(exp, self)
+    }
+}
+
+impl<T> Default for Matrix<T> {
+    fn default() -> Self {
+        Self { rows: Vec::new() }
+    }
+}
+
+impl<T: Field, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T> {
+    fn from(value: [[T; C]; R]) -> Self {
         let rows = Vec::from(value.map(Vector::from));
         Self { rows }
     }
@@ -409,8 +587,8 @@ impl<const R: usize, const C: usize> From<[[i32; C]; R]> for Matrix {
     }
 }
 
-impl From<Vec<Vec<Fraction>>> for Matrix {
-    fn from(value: Vec<Vec<Fraction>>) -> Self {
+impl<T: Field> From<Vec<Vec<T>>> for Matrix<T> {
+    fn from(value: Vec<Vec<T>>) -> Self {
         let rows = value.into_iter().map(Vector::from).collect();
         Self { rows }
     }
@@ -423,39 +601,39 @@ impl From<Vec<Vec<i32>>> for Matrix {
     }
 }
 
-impl Index<usize> for Matrix {
-    type Output = Vector;
+impl<T> Index<usize> for Matrix<T> {
+    type Output = Vector<T>;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.rows[index]
     }
 }
 
-impl IndexMut<usize> for Matrix {
+impl<T> IndexMut<usize> for Matrix<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.rows[index]
     }
 }
 
-impl Display for Matrix {
+impl<T: Display> Display for Matrix<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         writeln!(f, "[")?;
 
         // calc the max width of element
         let mut width = 0;
-        for i in 0..self.row_size() {
-            for j in 0..self.col_size() {
-                width = width.max(format!("{}", self[i][j]).len());
+        for row in &self.rows {
+            for e in &row.elements {
+                width = width.max(format!("{}", e).len());
             }
         }
 
         // align right, fill with space
-        for i in 0..self.row_size() {
-            for j in 0..self.col_size() {
+        for row in &self.rows {
+            for (j, e) in row.elements.iter().enumerate() {
                 if j != 0 {
                     write!(f, " ")?;
                 }
-                write!(f, "{:>width$}", format!("{}", self[i][j]))?;
+                write!(f, "{:>width$}", format!("{}", e))?;
             }
             writeln!(f)?;
         }
@@ -464,75 +642,134 @@ impl Display for Matrix {
     }
 }
 
-auto_ops::impl_op_ex!(+=|a: &mut Matrix, b: &Matrix| {
-    detail::check_size(a.row_size(), b.row_size());
-    detail::check_size(a.col_size(), b.col_size());
+impl<T: Field> AddAssign<&Matrix<T>> for Matrix<T> {
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        detail::unwrap(detail::check_shape(self.shape(), rhs.shape()));
 
-    for r in 0..a.row_size() {
-        a[r] += &b[r];
+        for r in 0..self.row_size() {
+            self[r] += &rhs[r];
+        }
     }
-});
+}
 
-auto_ops::impl_op_ex!(+|a: &Matrix, b: &Matrix| -> Matrix {
-    let mut a = a.clone();
-    a += b;
-    a
-});
+impl<T: Field> AddAssign<Matrix<T>> for Matrix<T> {
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self += &rhs;
+    }
+}
 
-auto_ops::impl_op_ex!(-=|a: &mut Matrix, b: &Matrix| {
-    detail::check_size(a.row_size(), b.row_size());
-    detail::check_size(a.col_size(), b.col_size());
+impl<T: Field> Add<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
 
-    for r in 0..a.row_size() {
-        a[r] -= &b[r];
+    fn add(self, rhs: &Matrix<T>) -> Self::Output {
+        detail::unwrap(self.try_add(rhs))
     }
-});
+}
 
-auto_ops::impl_op_ex!(-|a: &Matrix, b: &Matrix| -> Matrix {
-    let mut a = a.clone();
-    a -= b;
-    a
-});
+forward_binop!(impl<T> Add, add for Matrix<T>, Matrix<T> => Matrix<T>);
 
-auto_ops::impl_op_ex!(*=|a: &mut Matrix, b: Fraction| {
-    for r in 0..a.row_size() {
-        a.rows[r] *= b;
-    }
-});
+impl<T: Field> SubAssign<&Matrix<T>> for Matrix<T> {
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        detail::unwrap(detail::check_shape(self.shape(), rhs.shape()));
 
-auto_ops::impl_op_ex_commutative!(*|a: Matrix, b: Fraction| -> Matrix {
-    let mut a = a;
-    a *= b;
-    a
-});
+        for r in 0..self.row_size() {
+            self[r] -= &rhs[r];
+        }
+    }
+}
 
-auto_ops::impl_op_ex!(*=|a: &mut Matrix, b: i32| {
-    for r in 0..a.row_size() {
-        a.rows[r] *= b;
+impl<T: Field> SubAssign<Matrix<T>> for Matrix<T> {
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs;
     }
-});
+}
 
-auto_ops::impl_op_ex_commutative!(*|a: Matrix, b: i32| -> Matrix {
-    let mut a = a;
-    a *= b;
-    a
-});
+impl<T: Field> Sub<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: &Matrix<T>) -> Self::Output {
+        detail::unwrap(self.try_sub(rhs))
+    }
+}
 
-auto_ops::impl_op_ex!(*|a: &Matrix, b: &Matrix| -> Matrix {
-    detail::check_size(a.col_size(), b.row_size());
+forward_binop!(impl<T> Sub, sub for Matrix<T>, Matrix<T> => Matrix<T>);
 
-    let mut result = Matrix::zeros(a.row_size(), b.col_size());
-    let rt = b.transpose();
-    for r in 0..a.row_size() {
-        for c in 0..b.col_size() {
-            result[r][c] = &a[r] * &rt[c];
+impl<T: Field> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        for r in 0..self.row_size() {
+            self.rows[r] *= &rhs;
         }
     }
-    result
-});
+}
+
+impl<T: Field> MulAssign<&T> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: &T) {
+        *self *= rhs.clone();
+    }
+}
+
+impl<T: Field> Mul<&T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &T) -> Self::Output {
+        let mut a = self.clone();
+        a *= rhs;
+        a
+    }
+}
+
+forward_binop!(impl<T> Mul, mul for Matrix<T>, T => Matrix<T>);
+
+impl<T: Field + From<i32>> MulAssign<i32> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self *= T::from(rhs);
+    }
+}
+
+impl<T: Field + From<i32>> Mul<i32> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        self * T::from(rhs)
+    }
+}
+
+impl<T: Field + From<i32>> Mul<i32> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<T: Field + From<i32>> Mul<&Matrix<T>> for i32 {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<T: Field + From<i32>> Mul<Matrix<T>> for i32 {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        &rhs * self
+    }
+}
+
+impl<T: Field> Mul<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        detail::unwrap(self.try_mul(rhs))
+    }
+}
+
+forward_binop!(impl<T> Mul, mul for Matrix<T>, Matrix<T> => Matrix<T>);
 
-impl IntoIterator for Matrix {
-    type Item = Vector;
+impl<T> IntoIterator for Matrix<T> {
+    type Item = Vector<T>;
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
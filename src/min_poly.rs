@@ -0,0 +1,47 @@
+use pyinrs::Fraction;
+
+use crate::{detail, frobenius::krylov, Matrix, MatrixError, Polynomial, Vector};
+
+impl Matrix {
+    /// Calculate the minimal polynomial of this matrix, the monic polynomial p of the lowest degree with `p(A) = 0`.
+    ///
+    /// It divides the characteristic polynomial and has the same roots.
+    pub fn min_poly(&self) -> Polynomial {
+        detail::unwrap(self.try_min_poly())
+    }
+
+    /// Calculate the minimal polynomial of this matrix, or an error if it is not square.
+    ///
+    /// Use the Krylov sequence `I, A, A^2, ...`: its first element that is a linear combination of the previous ones gives
+    /// the minimal polynomial. The minimal polynomial of the empty matrix is 1.
+    pub fn try_min_poly(&self) -> Result<Polynomial, MatrixError> {
+        detail::check_square(self)?;
+
+        // the matrices are flattened to vectors of size n^2 in row-major order, the step is M -> A M
+        let n = self.row_size();
+        if n == 0 {
+            return Ok(Polynomial::from([1]));
+        }
+        let identity = Vector::from_fn(n * n, |k| Fraction::from((k / n == k % n) as i32));
+        let step = |m: &Vector| Vector::from_fn(n * n, |k| (0..n).fold(Fraction::new(), |acc, j| acc + self[k / n][j] * m[j * n + k % n]));
+        Ok(krylov(identity, step).1)
+    }
+
+    /// Returns `true` if `A^k = 0` for some k, i.e. the square-free part of the minimal polynomial is x.
+    pub fn is_nilpotent(&self) -> bool {
+        if self.row_size() != self.col_size() {
+            return false;
+        }
+
+        self.is_empty() || self.min_poly().square_free() == Polynomial::from([0, 1])
+    }
+
+    /// Returns `true` if `A^2 = A`, i.e. the minimal polynomial divides `x^2 - x`.
+    pub fn is_idempotent(&self) -> bool {
+        if self.row_size() != self.col_size() {
+            return false;
+        }
+
+        (Polynomial::from([0, -1, 1]) % self.min_poly()).is_zero()
+    }
+}
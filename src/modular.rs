@@ -0,0 +1,157 @@
+use pyinrs::Fraction;
+
+use crate::{detail, gf::mul_mod, Matrix, MatrixError, Vector};
+
+// Return the inverse of a modulo m, or None if they are not coprime, use extended Euclidean algorithm.
+fn inverse_mod(a: u64, m: u64) -> Option<u64> {
+    let (mut r0, mut r1) = ((a % m) as i128, m as i128);
+    let (mut s0, mut s1) = (1i128, 0i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    (r0 == 1).then(|| s0.rem_euclid(m as i128) as u64)
+}
+
+// Reduce a fraction modulo m, or an error if the denominator is not coprime with m.
+fn residue(value: Fraction, m: u64) -> Result<u64, MatrixError> {
+    let num = value.numerator().rem_euclid(m as i128) as u64;
+    let den = value.denominator().rem_euclid(m as i128) as u64;
+    let inv = inverse_mod(den, m).ok_or(MatrixError::NotInvertible { modulus: m })?;
+    Ok(mul_mod(num, inv, m))
+}
+
+// Return the product of two square matrices with elements in `0..m`, modulo m.
+fn mul_matrix_mod(a: &Matrix, b: &Matrix, m: u64) -> Matrix {
+    let n = a.row_size();
+    Matrix::from_fn(n, n, |r, c| {
+        let mut sum = 0;
+        for k in 0..n {
+            let product = mul_mod(a[r][k].numerator() as u64, b[k][c].numerator() as u64, m);
+            sum = ((sum as u128 + product as u128) % m as u128) as u64;
+        }
+        sum.into()
+    })
+}
+
+impl Matrix {
+    /// Calculate the inverse of this matrix modulo m, with elements in `0..m`.
+    ///
+    /// Return `None` if the matrix is not invertible modulo m, i.e. `gcd(det, m) != 1`.
+    pub fn inv_mod(&self, m: u64) -> Option<Self> {
+        match self.try_inv_mod(m) {
+            Ok(inv) => Some(inv),
+            Err(MatrixError::NotInvertible { .. }) => None,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Calculate the inverse `det^-1 adj` of this matrix modulo m, with elements in `0..m`, or an error if it is not square,
+    /// not invertible modulo m, m is 0, or the elements are too large modulo m for det and adj to be calculated exactly.
+    pub fn try_inv_mod(&self, m: u64) -> Result<Self, MatrixError> {
+        detail::check_square(self)?;
+        if m == 0 {
+            return Err(MatrixError::ZeroModulus);
+        }
+
+        // reduce the elements to the least absolute residues first, so that det and adj are calculated on small integers,
+        // every minor of an n x n matrix with elements at most b in absolute value is at most (n b)^n, and Bareiss multiplies two
+        let n = self.row_size();
+        let half = Fraction::from((m / 2) as i128);
+        let a = self.try_reduce_mod(m)?.map(|&e| if e > half { e - Fraction::from(m as i128) } else { e });
+        let b = a
+            .rows
+            .iter()
+            .flat_map(|row| row.elements.iter())
+            .map(|e| e.numerator().unsigned_abs())
+            .max()
+            .unwrap_or(0);
+        (n as u128 * b).checked_pow(n as u32).filter(|&h| h < 1 << 62).ok_or(MatrixError::Overflow)?;
+
+        let det = residue(a.det(), m)?;
+        let det_inv = inverse_mod(det, m).ok_or(MatrixError::NotInvertible { modulus: m })?;
+
+        let adj = a.adj();
+        let mut inv = Self::zeros(n, n);
+        for r in 0..n {
+            for c in 0..n {
+                inv[r][c] = Fraction::from(mul_mod(residue(adj[r][c], m)?, det_inv, m));
+            }
+        }
+        Ok(inv)
+    }
+
+    /// Solve the system of linear equations `A x = b` modulo m, with elements of the solution in `0..m`.
+    ///
+    /// Return `None` if the matrix is not invertible modulo m, i.e. `gcd(det, m) != 1`.
+    pub fn solve_mod(&self, b: &Vector, m: u64) -> Option<Vector> {
+        match self.try_solve_mod(b, m) {
+            Ok(x) => Some(x),
+            Err(MatrixError::NotInvertible { .. }) => None,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Solve the system of linear equations `A x = b` modulo m, with elements of the solution in `0..m`,
+    /// or an error if the matrix is not square, not invertible modulo m, m is 0, the dimensions mismatch, or [`Matrix::try_inv_mod`] overflows.
+    pub fn try_solve_mod(&self, b: &Vector, m: u64) -> Result<Vector, MatrixError> {
+        detail::check_size(self.row_size(), b.size())?;
+
+        let inv = self.try_inv_mod(m)?;
+        let b = b.elements.iter().map(|&e| residue(e, m)).collect::<Result<Vec<_>, _>>()?;
+
+        // x = A^-1 b mod m, accumulate modulo m so that nothing overflows
+        let mut x = Vector::zeros(b.len());
+        for (r, row) in inv.rows.iter().enumerate() {
+            let mut sum = 0;
+            for (e, &be) in row.elements.iter().zip(&b) {
+                sum = ((sum as u128 + mul_mod(e.numerator() as u64, be, m) as u128) % m as u128) as u64;
+            }
+            x[r] = sum.into();
+        }
+        Ok(x)
+    }
+
+    /// Calculate the power `A^exp` of this matrix modulo m, with elements in `0..m`.
+    pub fn pow_mod(&self, exp: u64, m: u64) -> Self {
+        detail::unwrap(self.try_pow_mod(exp, m))
+    }
+
+    /// Calculate the power `A^exp` of this matrix modulo m, with elements in `0..m`,
+    /// or an error if it is not square, a denominator is not coprime with m, or m is 0.
+    ///
+    /// Use binary exponentiation, every product is reduced modulo m so that nothing overflows.
+    pub fn try_pow_mod(&self, exp: u64, m: u64) -> Result<Self, MatrixError> {
+        detail::check_square(self)?;
+        if m == 0 {
+            return Err(MatrixError::ZeroModulus);
+        }
+
+        let n = self.row_size();
+        let mut base = self.try_reduce_mod(m)?;
+        let mut result = Self::identity(n).try_reduce_mod(m)?;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = mul_matrix_mod(&result, &base, m);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = mul_matrix_mod(&base, &base, m);
+            }
+        }
+        Ok(result)
+    }
+
+    // Reduce the elements modulo m, or an error if a denominator is not coprime with m.
+    fn try_reduce_mod(&self, m: u64) -> Result<Self, MatrixError> {
+        let mut a = Self::zeros(self.row_size(), self.col_size());
+        for r in 0..a.row_size() {
+            for c in 0..a.col_size() {
+                a[r][c] = residue(self[r][c], m)?.into();
+            }
+        }
+        Ok(a)
+    }
+}
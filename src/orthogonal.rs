@@ -0,0 +1,62 @@
+use pyinrs::Fraction;
+
+use crate::{detail, Matrix, MatrixError, Vector};
+
+impl Vector {
+    /// Gram-Schmidt orthogonalization in exact arithmetic, return an orthogonal and not normalized basis of the span of the vectors.
+    ///
+    /// The basis vectors are in the order of the input, a vector that depends linearly on the vectors before it is skipped.
+    pub fn gram_schmidt(vectors: &[Self]) -> Vec<Self> {
+        detail::unwrap(Self::try_gram_schmidt(vectors))
+    }
+
+    /// Gram-Schmidt orthogonalization in exact arithmetic, return an orthogonal and not normalized basis of the span of the vectors,
+    /// or an error if the vectors are empty or their sizes mismatch.
+    pub fn try_gram_schmidt(vectors: &[Self]) -> Result<Vec<Self>, MatrixError> {
+        for v in vectors {
+            detail::check_empty(v.size())?;
+            detail::check_size(vectors[0].size(), v.size())?;
+        }
+
+        let (orthogonal, _) = Matrix { rows: vectors.to_vec() }.gram_schmidt();
+        Ok(orthogonal.rows.into_iter().filter(|v| !v.is_zero()).collect())
+    }
+}
+
+impl Matrix {
+    /// Gram-Schmidt orthogonalization of the rows, return (B*, μ) such that `B = μ B*`, in exact arithmetic.
+    ///
+    /// The rows of B* are orthogonal and not normalized, `b*_i = b_i - sum_(j<i) μ_ij b*_j` with `μ_ij = <b_i, b*_j> / <b*_j, b*_j>`.
+    /// μ is unit lower triangular. If a row depends linearly on the rows above it, its row in B* is zero,
+    /// and the coefficients along that zero row are 0.
+    pub fn gram_schmidt(&self) -> (Self, Self) {
+        let n = self.row_size();
+        let mut orthogonal = Self::zeros(n, self.col_size());
+        let mut mu = Self::identity(n);
+        let mut norms = vec![Fraction::new(); n];
+        for i in 0..n {
+            let mut v = self[i].clone();
+            for j in 0..i {
+                if norms[j] != 0.into() {
+                    mu[i][j] = &self[i] * &orthogonal[j] / norms[j];
+                    v -= &orthogonal[j] * mu[i][j];
+                }
+            }
+            norms[i] = &v * &v;
+            orthogonal[i] = v;
+        }
+        (orthogonal, mu)
+    }
+
+    /// QR factorization in exact arithmetic, return (Q, D, R) such that `A = Q R` and `D = Q^T Q`.
+    ///
+    /// Q has orthogonal and not normalized columns, the Gram-Schmidt orthogonalization of the columns of A, D is diagonal
+    /// with the squared norms of the columns of Q, and R is unit upper triangular. The usual factors with orthonormal columns
+    /// are `Q D^(-1/2)` and `D^(1/2) R`, which are irrational in general. A column that depends linearly on the columns
+    /// before it gives a zero column in Q and a zero on the diagonal of D.
+    pub fn qr_exact(&self) -> (Self, Self, Self) {
+        let (orthogonal, mu) = self.transpose().gram_schmidt();
+        let d = &orthogonal * &orthogonal.transpose();
+        (orthogonal.transpose(), d, mu.transpose())
+    }
+}
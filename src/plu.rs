@@ -0,0 +1,175 @@
+use pyinrs::Fraction;
+
+use crate::{detail, Field, Matrix, MatrixError, Vector};
+
+/// PLU decomposition `P A = L U` of a matrix, with row pivoting.
+///
+/// For an m x n matrix A, P is an m x m permutation matrix, L is an m x m unit lower triangular matrix,
+/// and U is an m x n upper triangular matrix. It exists for any matrix, including singular and rectangular ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Plu<T = Fraction> {
+    /// The row permutation: the i-th row of `P A` is the `perm[i]`-th row of A.
+    pub perm: Vec<usize>,
+
+    /// The unit lower triangular factor.
+    pub l: Matrix<T>,
+
+    /// The upper triangular factor.
+    pub u: Matrix<T>,
+}
+
+impl<T: Field> Plu<T> {
+    /// Return the permutation matrix P.
+    pub fn p(&self) -> Matrix<T> {
+        let n = self.perm.len();
+        let mut p = Matrix::filled(n, n, self.u.zero_like());
+        for (i, &j) in self.perm.iter().enumerate() {
+            p[i][j] = self.u.one_like();
+        }
+        p
+    }
+
+    /// Return the sign of the permutation, 1 if even and -1 if odd.
+    pub fn sign(&self) -> T {
+        // a cycle of length k is made of k - 1 transpositions, so a cycle of even length flips the sign
+        let mut visited = vec![false; self.perm.len()];
+        let mut odd = false;
+        for i in 0..self.perm.len() {
+            let mut len = 0;
+            let mut j = i;
+            while !visited[j] {
+                visited[j] = true;
+                j = self.perm[j];
+                len += 1;
+            }
+            if len != 0 && len % 2 == 0 {
+                odd = !odd;
+            }
+        }
+
+        let one = self.u.one_like();
+        if odd {
+            -one
+        } else {
+            one
+        }
+    }
+
+    /// Calculate the determinant of the original matrix.
+    pub fn det(&self) -> T {
+        detail::unwrap(self.try_det())
+    }
+
+    /// Calculate the determinant of the original matrix, or an error if it is not square.
+    pub fn try_det(&self) -> Result<T, MatrixError> {
+        detail::check_square(&self.u)?;
+
+        let mut det = self.sign();
+        for i in 0..self.u.row_size() {
+            det *= self.u[i][i].clone();
+        }
+        Ok(det)
+    }
+
+    /// Solve the system of linear equations `A x = b`, where A is the original square nonsingular matrix.
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        detail::unwrap(self.try_solve(b))
+    }
+
+    /// Solve the system of linear equations `A x = b`, or an error if A is not square, singular, or the dimensions mismatch.
+    pub fn try_solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        detail::check_square(&self.u)?;
+        detail::check_size(self.u.row_size(), b.size())?;
+
+        let n = self.u.row_size();
+        if (0..n).any(|i| self.u[i][i].is_zero()) {
+            return Err(MatrixError::Singular);
+        }
+
+        // forward substitution: L y = P b
+        let mut y = Vector::filled(n, b.zero_like());
+        for i in 0..n {
+            let mut sum = b[self.perm[i]].clone();
+            for j in 0..i {
+                sum -= self.l[i][j].clone() * y[j].clone();
+            }
+            y[i] = sum;
+        }
+
+        // back substitution: U x = y
+        let mut x = Vector::filled(n, b.zero_like());
+        for i in (0..n).rev() {
+            let mut sum = y[i].clone();
+            for j in i + 1..n {
+                sum -= self.u[i][j].clone() * x[j].clone();
+            }
+            x[i] = sum / self.u[i][i].clone();
+        }
+
+        Ok(x)
+    }
+
+    /// Solve the system of linear equations `A X = B`, where A is the original square nonsingular matrix.
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Matrix<T> {
+        detail::unwrap(self.try_solve_matrix(b))
+    }
+
+    /// Solve the system of linear equations `A X = B`, or an error if A is not square, singular, or the dimensions mismatch.
+    pub fn try_solve_matrix(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.u.row_size() != b.row_size() {
+            return Err(MatrixError::ShapeMismatch {
+                lhs: self.u.shape(),
+                rhs: b.shape(),
+            });
+        }
+
+        // solve column by column
+        let columns = b.transpose().rows.iter().map(|c| self.try_solve(c)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Matrix { rows: columns }.transpose())
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// PLU decomposition with row pivoting, return the reusable factorization `P A = L U`.
+    pub fn plu(&self) -> Plu<T> {
+        let (m, n) = self.shape();
+        let mut perm = (0..m).collect::<Vec<_>>();
+        let mut l = Self::scalar(m, self.one_like());
+        let mut u = self.clone();
+
+        for k in 0..usize::min(m, n) {
+            // find the first non-zero element in column k as the pivot, skip the column if there is none
+            let Some(p) = (k..m).find(|&i| !u[i][k].is_zero()) else {
+                continue;
+            };
+
+            // swap the rows, including the computed part of L
+            if p != k {
+                u.e_row_swap(k, p);
+                perm.swap(k, p);
+                for j in 0..k {
+                    let t = l[k][j].clone();
+                    l[k][j] = l[p][j].clone();
+                    l[p][j] = t;
+                }
+            }
+
+            // eliminate elements below the pivot, record the multipliers in L
+            for i in k + 1..m {
+                let factor = u[i][k].clone() / u[k][k].clone();
+                if !factor.is_zero() {
+                    l[i][k] = factor.clone();
+                    u.e_row_sum(i, k, -factor);
+                }
+            }
+        }
+
+        Plu { perm, l, u }
+    }
+
+    /// PLU decomposition with row pivoting, return (P, L, U) such that `P A = L U`.
+    pub fn plu_decomposition(&self) -> (Self, Self, Self) {
+        let plu = self.plu();
+        (plu.p(), plu.l, plu.u)
+    }
+}
@@ -0,0 +1,395 @@
+use std::fmt::Display;
+
+use pyinrs::Fraction;
+
+use crate::{detail, Matrix, MatrixError};
+
+/// Polynomial with fraction coefficients.
+///
+/// Coefficients are stored in ascending order of degree: `[c0, c1, c2]` is `c0 + c1 x + c2 x^2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Polynomial {
+    // no trailing zeros, the zero polynomial has no coefficients
+    coeffs: Vec<Fraction>,
+}
+
+impl Polynomial {
+    /// Create a new polynomial object, the zero polynomial.
+    pub fn new() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    /// Create the monomial `coeff * x^degree`.
+    pub fn monomial(coeff: Fraction, degree: usize) -> Self {
+        let mut coeffs = vec![Fraction::new(); degree + 1];
+        coeffs[degree] = coeff;
+        Self::from(coeffs)
+    }
+
+    /// Return the degree of the polynomial, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Return the coefficients in ascending order of degree, without trailing zeros.
+    pub fn coeffs(&self) -> &[Fraction] {
+        &self.coeffs
+    }
+
+    /// Return the coefficient of `x^i`.
+    pub fn coeff(&self, i: usize) -> Fraction {
+        self.coeffs.get(i).copied().unwrap_or_default()
+    }
+
+    /// Return the coefficient of the highest degree term, 0 for the zero polynomial.
+    pub fn leading_coeff(&self) -> Fraction {
+        self.coeffs.last().copied().unwrap_or_default()
+    }
+
+    /// Returns `true` if this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Returns `true` if the leading coefficient is 1.
+    pub fn is_monic(&self) -> bool {
+        self.leading_coeff() == 1.into()
+    }
+
+    /// Return the polynomial divided by its leading coefficient, the zero polynomial is returned unchanged.
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        self * (Fraction::from(1) / self.leading_coeff())
+    }
+
+    /// Evaluate the polynomial at `x`, use Horner's method.
+    pub fn eval(&self, x: Fraction) -> Fraction {
+        self.coeffs.iter().rev().fold(Fraction::new(), |acc, &c| acc * x + c)
+    }
+
+    /// Evaluate the polynomial at the square matrix `a`, use Horner's method: `p(A) = (...(c_n A + c_(n-1) I) A + ...) + c_0 I`.
+    pub fn eval_matrix(&self, a: &Matrix) -> Matrix {
+        detail::unwrap(self.try_eval_matrix(a))
+    }
+
+    /// Evaluate the polynomial at the square matrix `a`, or an error if it is not square.
+    pub fn try_eval_matrix(&self, a: &Matrix) -> Result<Matrix, MatrixError> {
+        detail::check_square(a)?;
+
+        let n = a.row_size();
+        Ok(self.coeffs.iter().rev().fold(Matrix::zeros(n, n), |acc, &c| acc * a + Matrix::identity(n) * c))
+    }
+
+    /// Return the derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        Self::from(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * Fraction::from(i as i128))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Return the quotient and remainder of the polynomial division, `self = q * divisor + r` with `deg r < deg divisor`.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let Some(d) = divisor.degree() else {
+            panic!("Error: Divide by zero.");
+        };
+
+        let mut r = self.clone();
+        let mut q = vec![Fraction::new(); self.coeffs.len().saturating_sub(d)];
+        while let Some(n) = r.degree().filter(|&n| n >= d) {
+            let factor = r.leading_coeff() / divisor.leading_coeff();
+            q[n - d] = factor;
+            r -= Self::monomial(factor, n - d) * divisor;
+        }
+
+        (Self::from(q), r)
+    }
+
+    /// Return the monic greatest common divisor of two polynomials, 0 if both are 0.
+    pub fn gcd(a: &Self, b: &Self) -> Self {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    /// Return the monic square-free part `p / gcd(p, p')`, which has the same roots as p, each of multiplicity 1.
+    ///
+    /// The zero polynomial is returned unchanged.
+    pub fn square_free(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        (self / &Self::gcd(self, &self.derivative())).monic()
+    }
+
+    /// Return the rational roots in increasing order, each with its multiplicity.
+    ///
+    /// Use the rational root theorem: after clearing the denominators and dividing out the content, a root `p/q` in lowest
+    /// terms has `p` dividing the lowest non-zero coefficient and `q` dividing the leading coefficient.
+    pub fn rational_roots(&self) -> Vec<(Fraction, usize)> {
+        detail::unwrap(self.try_rational_roots())
+    }
+
+    /// Return the rational roots in increasing order, each with its multiplicity, or an error if the coefficients of the
+    /// primitive integer polynomial overflow or the candidates take more than 2^22 trial divisions to find.
+    ///
+    /// Only the divisors `p` of the constant term with `|p/q|` within Kioustelidis' bound of the positive roots of `f(x)`,
+    /// or of `f(-x)` for a negative candidate, are candidates. They are tested by exact synthetic division.
+    pub fn try_rational_roots(&self) -> Result<Vec<(Fraction, usize)>, MatrixError> {
+        let mut roots = Vec::new();
+        if self.is_zero() {
+            return Ok(roots);
+        }
+
+        // the root 0, divide by x^k
+        let k = self.coeffs.iter().take_while(|&&c| c == 0.into()).count();
+        if k > 0 {
+            roots.push((Fraction::new(), k));
+        }
+        let mut a = primitive(&self.coeffs[k..])?;
+
+        // the root of a linear polynomial is at hand, however large the coefficients are
+        if a.len() == 2 {
+            roots.push((Fraction::from((-a[0], a[1])), 1));
+            roots.sort();
+            return Ok(roots);
+        }
+
+        let reflected = a.iter().enumerate().map(|(i, &c)| if i % 2 == 1 { -c } else { c }).collect::<Vec<_>>();
+        let (positive, negative) = (root_bound(&a), root_bound(&reflected));
+        let dens = divisors(a[a.len() - 1], u128::MAX)?;
+        let nums = divisors(a[0], positive.max(negative).saturating_mul(a[a.len() - 1].unsigned_abs()))?;
+        for num in nums {
+            for &den in &dens {
+                // skip duplicates like 2/2 and 1/1 that are not in lowest terms
+                if Fraction::from((num, den)).denominator() != den {
+                    continue;
+                }
+                for (p, bound) in [(num, positive), (-num, negative)] {
+                    if num as u128 > bound.saturating_mul(den as u128) {
+                        continue;
+                    }
+                    let mut multiplicity = 0;
+                    while let Some(quotient) = deflate(&a, p, den)? {
+                        a = quotient;
+                        multiplicity += 1;
+                    }
+                    if multiplicity > 0 {
+                        roots.push((Fraction::from((p, den)), multiplicity));
+                    }
+                }
+            }
+        }
+
+        roots.sort();
+        Ok(roots)
+    }
+
+    // Remove the trailing zero coefficients.
+    fn trim(mut self) -> Self {
+        while self.coeffs.last() == Some(&Fraction::new()) {
+            self.coeffs.pop();
+        }
+        self
+    }
+}
+
+// Return the coefficients of the primitive integer polynomial with the same roots, or an error if they overflow.
+fn primitive(coeffs: &[Fraction]) -> Result<Vec<i128>, MatrixError> {
+    let gcd = |a: i128, b: i128| Fraction::gcd(a.into(), b.into()).numerator();
+    let lcm = coeffs
+        .iter()
+        .try_fold(1i128, |lcm, c| (lcm / gcd(lcm, c.denominator())).checked_mul(c.denominator()))
+        .ok_or(MatrixError::Overflow)?;
+    let scaled = coeffs
+        .iter()
+        .map(|c| c.numerator().checked_mul(lcm / c.denominator()).filter(|&c| c != i128::MIN))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(MatrixError::Overflow)?;
+    let content = scaled.iter().fold(0, |content, &c| gcd(content, c));
+    Ok(scaled.into_iter().map(|c| c / content).collect())
+}
+
+// Divide the integer polynomial by `q x - p` with q > 0 and gcd(p, q) = 1, return the quotient if `p/q` is a root.
+//
+// By Gauss's lemma the quotient has integer coefficients, so `p/q` is not a root at the first inexact division. Divide from
+// the leading coefficient if |p/q| <= 1, otherwise from the constant term, then each partial quotient is an exact value
+// bounded by the sum of |a_i|, so an overflow means the coefficients themselves are too large.
+fn deflate(a: &[i128], p: i128, q: i128) -> Result<Option<Vec<i128>>, MatrixError> {
+    if p.unsigned_abs() > q.unsigned_abs() {
+        // x^n f(1/x) = (|p| x - sgn(p) q) x^(n-1) (-sgn(p)) g(1/x)
+        let reversed = a.iter().rev().copied().collect::<Vec<_>>();
+        let quotient = deflate(&reversed, q * p.signum(), p.abs())?;
+        return Ok(quotient.map(|b| b.into_iter().rev().map(|c| -c * p.signum()).collect()));
+    }
+
+    // the coefficients of x^(i+1) give q b_i - p b_(i+1) = a_(i+1), and the remainder is a_0 + p b_0
+    let n = a.len() - 1;
+    let mut b = vec![0; n];
+    let mut carry = a[n];
+    for i in (0..n).rev() {
+        if carry % q != 0 {
+            return Ok(None);
+        }
+        b[i] = carry / q;
+        carry = p.checked_mul(b[i]).and_then(|e| e.checked_add(a[i])).ok_or(MatrixError::Overflow)?;
+    }
+    Ok((carry == 0).then_some(b))
+}
+
+// Return Kioustelidis' bound `2 max (|a_i| / |a_n|)^(1 / (n - i))` of the positive roots of the integer polynomial, over the
+// coefficients a_i of the opposite sign to a_n, or 0 if there are none. It is rounded up with a margin for the rounding errors.
+fn root_bound(a: &[i128]) -> u128 {
+    let n = a.len() - 1;
+    let bound = (0..n)
+        .filter(|&i| a[i].signum() == -a[n].signum())
+        .map(|i| (a[i] as f64 / a[n] as f64).abs().powf(1.0 / (n - i) as f64))
+        .fold(0.0, f64::max);
+    if bound == 0.0 {
+        0
+    } else {
+        (2.0 * bound * (1.0 + 1e-9)) as u128 + 1
+    }
+}
+
+// Trial division stops at this bound, so that a coefficient near i128::MAX does not take ~10^19 steps.
+const TRIAL_DIVISION_LIMIT: u128 = 1 << 22;
+
+// Return the positive divisors of n != 0 up to the limit in increasing order, by trial division up to min(limit, sqrt |n|),
+// or an error if that is beyond the trial division limit.
+fn divisors(n: i128, limit: u128) -> Result<Vec<i128>, MatrixError> {
+    let m = n.unsigned_abs();
+    let steps = limit.min(m.isqrt());
+    if steps > TRIAL_DIVISION_LIMIT {
+        return Err(MatrixError::FactorizationLimit { value: n });
+    }
+
+    let mut divisors = Vec::new();
+    for d in (1..=steps).filter(|&d| m.is_multiple_of(d)) {
+        divisors.push(d as i128);
+        if m / d <= limit {
+            divisors.push((m / d) as i128);
+        }
+    }
+    divisors.sort();
+    divisors.dedup();
+    Ok(divisors)
+}
+
+impl From<Vec<Fraction>> for Polynomial {
+    fn from(value: Vec<Fraction>) -> Self {
+        Self { coeffs: value }.trim()
+    }
+}
+
+impl<const N: usize> From<[Fraction; N]> for Polynomial {
+    fn from(value: [Fraction; N]) -> Self {
+        Self::from(Vec::from(value))
+    }
+}
+
+impl<const N: usize> From<[i32; N]> for Polynomial {
+    fn from(value: [i32; N]) -> Self {
+        Self::from(Vec::from(value.map(Fraction::from)))
+    }
+}
+
+impl From<Fraction> for Polynomial {
+    fn from(value: Fraction) -> Self {
+        Self::from(vec![value])
+    }
+}
+
+impl Display for Polynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // from the highest degree term, e.g. "x^2 - 1/2x + 3"
+        for (i, c) in self.coeffs.iter().enumerate().rev().filter(|(_, &c)| c != 0.into()) {
+            let sign = if *c < 0.into() { "-" } else { "+" };
+            if i == self.coeffs.len() - 1 {
+                if sign == "-" {
+                    write!(f, "-")?;
+                }
+            } else {
+                write!(f, " {sign} ")?;
+            }
+
+            let c = c.abs();
+            if c != 1.into() || i == 0 {
+                write!(f, "{c}")?;
+            }
+            match i {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{i}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+auto_ops::impl_op_ex!(-|a: &Polynomial| -> Polynomial { a * Fraction::from(-1) });
+
+auto_ops::impl_op_ex!(+=|a: &mut Polynomial, b: &Polynomial| {
+    if a.coeffs.len() < b.coeffs.len() {
+        a.coeffs.resize(b.coeffs.len(), Fraction::new());
+    }
+    for (i, &c) in b.coeffs.iter().enumerate() {
+        a.coeffs[i] += c;
+    }
+    *a = std::mem::take(a).trim();
+});
+
+auto_ops::impl_op_ex!(+|a: &Polynomial, b: &Polynomial| -> Polynomial {
+    let mut a = a.clone();
+    a += b;
+    a
+});
+
+auto_ops::impl_op_ex!(-=|a: &mut Polynomial, b: &Polynomial| {
+    *a += -b;
+});
+
+auto_ops::impl_op_ex!(-|a: &Polynomial, b: &Polynomial| -> Polynomial {
+    let mut a = a.clone();
+    a -= b;
+    a
+});
+
+auto_ops::impl_op_ex!(*|a: &Polynomial, b: &Polynomial| -> Polynomial {
+    if a.is_zero() || b.is_zero() {
+        return Polynomial::new();
+    }
+
+    let mut coeffs = vec![Fraction::new(); a.coeffs.len() + b.coeffs.len() - 1];
+    for (i, &x) in a.coeffs.iter().enumerate() {
+        for (j, &y) in b.coeffs.iter().enumerate() {
+            coeffs[i + j] += x * y;
+        }
+    }
+    Polynomial::from(coeffs)
+});
+
+auto_ops::impl_op_ex!(*=|a: &mut Polynomial, b: &Polynomial| {
+    *a = &*a * b;
+});
+
+auto_ops::impl_op_ex_commutative!(*|a: &Polynomial, b: Fraction| -> Polynomial { Polynomial::from(a.coeffs.iter().map(|&c| c * b).collect::<Vec<_>>()) });
+
+auto_ops::impl_op_ex!(/|a: &Polynomial, b: &Polynomial| -> Polynomial { a.div_rem(b).0 });
+
+auto_ops::impl_op_ex!(%|a: &Polynomial, b: &Polynomial| -> Polynomial { a.div_rem(b).1 });
@@ -0,0 +1,94 @@
+use crate::{detail, Field, Matrix, MatrixError, Vector};
+
+/// Return the orthogonal projection matrix onto the span of the vectors.
+pub fn projector<T: Field>(vectors: &[Vector<T>]) -> Matrix<T> {
+    detail::unwrap(try_projector(vectors))
+}
+
+/// Return the orthogonal projection matrix onto the span of the vectors, or an error if there are none, their sizes mismatch,
+/// or the span has no orthogonal projector.
+///
+/// The vectors may be linearly dependent, the projector only depends on their span. Over a finite field a span can contain
+/// non-zero vectors orthogonal to all of it, e.g. `(1, 2)` in GF(5), then the Gram matrix is singular and there is no projector.
+pub fn try_projector<T: Field>(vectors: &[Vector<T>]) -> Result<Matrix<T>, MatrixError> {
+    detail::check_empty(vectors.len())?;
+    for v in vectors {
+        detail::check_size(vectors[0].size(), v.size())?;
+    }
+
+    projector_of_basis(vectors[0].zero_like(), vectors[0].size(), Matrix { rows: vectors.to_vec() }.row_space())
+}
+
+// Return the n x n projector `B^T (B B^T)^-1 B` onto the span of the basis, the rows of B, or the zero matrix filled with `zero`
+// for an empty basis, or an error if the Gram matrix `B B^T` is singular, which is only possible over a finite field.
+fn projector_of_basis<T: Field>(zero: T, n: usize, basis: Vec<Vector<T>>) -> Result<Matrix<T>, MatrixError> {
+    if basis.is_empty() {
+        return Ok(Matrix::filled(n, n, zero));
+    }
+
+    let b = Matrix { rows: basis };
+    let t = b.transpose();
+    let gram = (&b * &t).try_inv()?;
+    Ok(t * gram * b)
+}
+
+impl<T: Field> Matrix<T> {
+    /// Return the orthogonal projection matrix `P = A (A^T A)^-1 A^T` onto the column space of this matrix.
+    ///
+    /// If the columns are linearly dependent, a basis of the column space takes the place of A.
+    pub fn projection_onto_column_space(&self) -> Self {
+        detail::unwrap(self.try_projection_onto_column_space())
+    }
+
+    /// Return the orthogonal projection matrix `P = A (A^T A)^-1 A^T` onto the column space of this matrix,
+    /// or an error if `A^T A` is singular for a basis of the column space, which is only possible over a finite field.
+    pub fn try_projection_onto_column_space(&self) -> Result<Self, MatrixError> {
+        projector_of_basis(self.zero_like(), self.row_size(), self.column_space())
+    }
+}
+
+impl<T: Field> Vector<T> {
+    /// Return the orthogonal projection `(<v, u> / <u, u>) u` of this vector onto the line spanned by u.
+    ///
+    /// The projection onto the zero vector is the zero vector.
+    pub fn project_onto(&self, u: &Self) -> Self {
+        detail::unwrap(self.try_project_onto(u))
+    }
+
+    /// Return the orthogonal projection `(<v, u> / <u, u>) u` of this vector onto the line spanned by u,
+    /// or an error if they are empty, the sizes mismatch, or `<u, u> = 0` for a non-zero u, which is only possible over a finite field.
+    pub fn try_project_onto(&self, u: &Self) -> Result<Self, MatrixError> {
+        let vu = self.try_dot(u)?;
+        let uu = u.try_dot(u)?;
+        if !uu.is_zero() {
+            Ok(u * (vu / uu))
+        } else if u.is_zero() {
+            Ok(Self::filled(u.size(), u.zero_like()))
+        } else {
+            Err(MatrixError::Singular)
+        }
+    }
+
+    /// Return the rejection `v - proj_u(v)` of this vector from u, the component orthogonal to u.
+    pub fn reject_from(&self, u: &Self) -> Self {
+        detail::unwrap(self.try_reject_from(u))
+    }
+
+    /// Return the rejection `v - proj_u(v)` of this vector from u, or an error if they are empty, the sizes mismatch,
+    /// or `<u, u> = 0` for a non-zero u.
+    pub fn try_reject_from(&self, u: &Self) -> Result<Self, MatrixError> {
+        Ok(self - &self.try_project_onto(u)?)
+    }
+
+    /// Return the reflection `2 proj_u(v) - v` of this vector across the line spanned by u.
+    pub fn reflect_across(&self, u: &Self) -> Self {
+        detail::unwrap(self.try_reflect_across(u))
+    }
+
+    /// Return the reflection `2 proj_u(v) - v` of this vector across the line spanned by u,
+    /// or an error if they are empty, the sizes mismatch, or `<u, u> = 0` for a non-zero u.
+    pub fn try_reflect_across(&self, u: &Self) -> Result<Self, MatrixError> {
+        let p = self.try_project_onto(u)?;
+        Ok(&(&p + &p) - self)
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::Display;
+
+use pyinrs::Fraction;
+
+use crate::{detail, Matrix, MatrixError, Vector};
+
+/// Classification of a real quadratic form by the signs of its values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Definiteness {
+    /// `q(x) > 0` for all `x != 0`.
+    PositiveDefinite,
+
+    /// `q(x) < 0` for all `x != 0`.
+    NegativeDefinite,
+
+    /// `q(x) >= 0` for all x, and `q(x) = 0` for some `x != 0`. The zero form is positive semidefinite.
+    PositiveSemidefinite,
+
+    /// `q(x) <= 0` for all x, and `q(x) = 0` for some `x != 0`, but the form is not zero.
+    NegativeSemidefinite,
+
+    /// `q` takes both positive and negative values.
+    Indefinite,
+}
+
+/// Quadratic form `q(x) = x^T A x` with a symmetric matrix A, and its polar bilinear form `b(x, y) = x^T A y`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuadraticForm {
+    matrix: Matrix,
+}
+
+impl QuadraticForm {
+    /// Create a quadratic form from its symmetric matrix.
+    ///
+    /// The coefficient of `x_i x_j` for `i != j` is `2 a_ij`, e.g. `x^2 + 4xy + y^2` is `[[1, 2], [2, 1]]`.
+    pub fn new(matrix: Matrix) -> Self {
+        detail::unwrap(Self::try_new(matrix))
+    }
+
+    /// Create a quadratic form from its symmetric matrix, or an error if it is not square or not symmetric.
+    pub fn try_new(matrix: Matrix) -> Result<Self, MatrixError> {
+        detail::check_square(&matrix)?;
+        if !matrix.is_symmetric() {
+            return Err(MatrixError::NotSymmetric);
+        }
+        Ok(Self { matrix })
+    }
+
+    /// Return the symmetric matrix of the form.
+    pub fn matrix(&self) -> &Matrix {
+        &self.matrix
+    }
+
+    /// Return the number of variables.
+    pub fn dim(&self) -> usize {
+        self.matrix.row_size()
+    }
+
+    /// Evaluate the form `x^T A x`.
+    pub fn eval(&self, x: &Vector) -> Fraction {
+        detail::unwrap(self.try_eval(x))
+    }
+
+    /// Evaluate the form `x^T A x`, or an error if the dimensions mismatch.
+    pub fn try_eval(&self, x: &Vector) -> Result<Fraction, MatrixError> {
+        self.try_bilinear(x, x)
+    }
+
+    /// Evaluate the polar bilinear form `x^T A y`, which is symmetric and has `b(x, x) = q(x)`.
+    pub fn bilinear(&self, x: &Vector, y: &Vector) -> Fraction {
+        detail::unwrap(self.try_bilinear(x, y))
+    }
+
+    /// Evaluate the polar bilinear form `x^T A y`, or an error if the dimensions mismatch.
+    pub fn try_bilinear(&self, x: &Vector, y: &Vector) -> Result<Fraction, MatrixError> {
+        detail::check_size(self.dim(), x.size())?;
+        detail::check_size(self.dim(), y.size())?;
+
+        Ok((0..self.dim()).fold(Fraction::new(), |acc, i| acc + x[i] * (&self.matrix[i] * y)))
+    }
+
+    /// Diagonalize the form by congruence, return (S, D) such that `S^T A S = D`, with S invertible and D diagonal.
+    ///
+    /// The change of variables `x = S y` turns the form into the sum of squares `d_1 y_1^2 + ... + d_n y_n^2`.
+    /// It comes from the LDL^T decomposition `P A P^T = L D L^T`, and a 2 x 2 block `[[0, b], [b, 0]]` of D is split
+    /// into `2b u^2 - 2b v^2` with `u + v` and `u - v` in place of its two variables.
+    pub fn diagonalize(&self) -> (Matrix, Matrix) {
+        let n = self.dim();
+        let ldl = self.matrix.ldl();
+
+        // M is block diagonal with [[1, 1], [1, -1]] for the 2 x 2 blocks, M^T D M is diagonal
+        let mut m = Matrix::identity(n);
+        let mut k = 0;
+        for &size in &ldl.blocks {
+            if size == 2 {
+                m[k][k + 1] = 1.into();
+                m[k + 1][k] = 1.into();
+                m[k + 1][k + 1] = (-1).into();
+            }
+            k += size;
+        }
+
+        // A = P^T L D L^T P, so S = P^T L^-T M
+        let s = ldl.p().transpose() * ldl.l.transpose().inv().unwrap() * &m;
+        let d = m.transpose() * &ldl.d * &m;
+        (s, d)
+    }
+
+    /// Return the inertia (positive, negative, zero), the numbers of positive, negative and zero squares in any diagonal form.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        self.matrix.inertia()
+    }
+
+    /// Return the rank, the number of non-zero squares in any diagonal form.
+    pub fn rank(&self) -> usize {
+        let (positive, negative, _) = self.inertia();
+        positive + negative
+    }
+
+    /// Return the signature, the number of positive squares minus the number of negative squares in any diagonal form.
+    pub fn signature(&self) -> isize {
+        let (positive, negative, _) = self.inertia();
+        positive as isize - negative as isize
+    }
+
+    /// Returns `true` if the form is degenerate, i.e. its matrix is singular and `b(x, y) = 0` for some `x != 0` and all y.
+    pub fn is_degenerate(&self) -> bool {
+        self.rank() < self.dim()
+    }
+
+    /// Classify the form by the signs of its values, from its inertia.
+    pub fn definiteness(&self) -> Definiteness {
+        let n = self.dim();
+        match self.inertia() {
+            (positive, _, _) if positive == n => Definiteness::PositiveDefinite,
+            (_, negative, _) if negative == n => Definiteness::NegativeDefinite,
+            (_, 0, _) => Definiteness::PositiveSemidefinite,
+            (0, _, _) => Definiteness::NegativeSemidefinite,
+            _ => Definiteness::Indefinite,
+        }
+    }
+}
+
+impl TryFrom<Matrix> for QuadraticForm {
+    type Error = MatrixError;
+
+    fn try_from(value: Matrix) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl Display for QuadraticForm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // terms x_i x_j with i <= j in order, e.g. "x1^2 - 4x1x2 + 1/2x2^2"
+        let mut first = true;
+        for i in 0..self.dim() {
+            for j in i..self.dim() {
+                let c = if i == j { self.matrix[i][i] } else { self.matrix[i][j] * Fraction::from(2) };
+                if c == 0.into() {
+                    continue;
+                }
+
+                if first {
+                    if c < 0.into() {
+                        write!(f, "-")?;
+                    }
+                } else {
+                    write!(f, " {} ", if c < 0.into() { "-" } else { "+" })?;
+                }
+                first = false;
+
+                let c = c.abs();
+                if c != 1.into() {
+                    write!(f, "{c}")?;
+                }
+                if i == j {
+                    write!(f, "x{}^2", i + 1)?;
+                } else {
+                    write!(f, "x{}x{}", i + 1, j + 1)?;
+                }
+            }
+        }
+
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use pyinrs::Fraction;
+
+use crate::{detail, space, Field, Matrix, MatrixError, Vector};
+
+/// Solution of a system of linear equations `A x = b`.
+///
+/// `T` is the element type, and `X` is the type of the unknown, [`Vector`] for a right-hand side vector and [`Matrix`] for a right-hand side matrix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Solution<T = Fraction, X = Vector<T>> {
+    /// The system has no solution.
+    NoSolution,
+
+    /// The system has a unique solution.
+    Unique(X),
+
+    /// The system has infinitely many solutions.
+    ///
+    /// The general solution is the particular solution plus any linear combination of the basis of
+    /// the homogeneous solutions `A x = 0` (for a right-hand side matrix, added to each column independently).
+    Infinite { particular: X, basis: Vec<Vector<T>> },
+}
+
+impl<T, X> Solution<T, X> {
+    /// Returns `true` if the system has at least one solution.
+    pub fn is_consistent(&self) -> bool {
+        !matches!(self, Self::NoSolution)
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    /// Solve the system of linear equations `A x = b`.
+    pub fn solve(&self, b: &Vector<T>) -> Solution<T> {
+        detail::unwrap(self.try_solve(b))
+    }
+
+    /// Solve the system of linear equations `A x = b`, or an error if the dimensions mismatch.
+    pub fn try_solve(&self, b: &Vector<T>) -> Result<Solution<T>, MatrixError> {
+        detail::check_size(self.row_size(), b.size())?;
+
+        // solve with b as a column matrix, then take the only column of the solution
+        let rhs = Matrix::from_fn(b.size(), 1, |i, _| b[i].clone());
+        let column = |x: Matrix<T>| Vector::from_fn(x.row_size(), |i| x[i][0].clone());
+        Ok(match self.try_solve_matrix(&rhs)? {
+            Solution::NoSolution => Solution::NoSolution,
+            Solution::Unique(x) => Solution::Unique(column(x)),
+            Solution::Infinite { particular, basis } => Solution::Infinite {
+                particular: column(particular),
+                basis,
+            },
+        })
+    }
+
+    /// Solve the system of linear equations `A X = B`.
+    pub fn solve_matrix(&self, b: &Self) -> Solution<T, Self> {
+        detail::unwrap(self.try_solve_matrix(b))
+    }
+
+    /// Solve the system of linear equations `A X = B`, or an error if the dimensions mismatch.
+    pub fn try_solve_matrix(&self, b: &Self) -> Result<Solution<T, Self>, MatrixError> {
+        if self.row_size() != b.row_size() {
+            return Err(MatrixError::ShapeMismatch {
+                lhs: self.shape(),
+                rhs: b.shape(),
+            });
+        }
+
+        // transform the augmented matrix [A:B] to reduced row echelon form, pivots only in columns of A
+        let (n, k) = (self.col_size(), b.col_size());
+        let mut augmented = self.clone();
+        augmented.expand_col(b.clone());
+        let (rref, pivots) = augmented.gauss_jordan(n);
+
+        // inconsistent if a zero row of A meets a non-zero element of B
+        for r in pivots.len()..rref.row_size() {
+            if (n..n + k).any(|c| !rref[r][c].is_zero()) {
+                return Ok(Solution::NoSolution);
+            }
+        }
+
+        // particular solution: free variables are set to 0
+        let mut particular = Self::filled(n, k, rref.zero_like());
+        for (r, &p) in pivots.iter().enumerate() {
+            for c in 0..k {
+                particular[p][c] = rref[r][n + c].clone();
+            }
+        }
+
+        if pivots.len() == n {
+            return Ok(Solution::Unique(particular));
+        }
+
+        // homogeneous solutions: one basis vector for each free variable
+        let basis = space::kernel_basis(&rref, &pivots, n);
+
+        Ok(Solution::Infinite { particular, basis })
+    }
+}
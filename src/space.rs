@@ -0,0 +1,79 @@
+use pyinrs::Fraction;
+
+use crate::{Field, Matrix, Vector};
+
+// Basis of the solutions of the homogeneous system, from the reduced row echelon form of its first n columns and the pivot columns.
+pub(crate) fn kernel_basis<T: Field>(rref: &Matrix<T>, pivots: &[usize], n: usize) -> Vec<Vector<T>> {
+    let mut basis = Vec::with_capacity(n - pivots.len());
+    for f in (0..n).filter(|c| !pivots.contains(c)) {
+        let mut v = Vector::filled(n, rref.zero_like());
+        v[f] = rref.one_like();
+        for (r, &p) in pivots.iter().enumerate() {
+            v[p] = -rref[r][f].clone();
+        }
+        basis.push(v);
+    }
+    basis
+}
+
+impl<T: Field> Matrix<T> {
+    /// Return a basis of the null space (kernel) of this matrix, the solutions of `A x = 0`.
+    pub fn null_space(&self) -> Vec<Vector<T>> {
+        let (rref, pivots) = self.gauss_jordan(self.col_size());
+        kernel_basis(&rref, &pivots, self.col_size())
+    }
+
+    /// Return a basis of the column space (image) of this matrix, made of the pivot columns of this matrix.
+    pub fn column_space(&self) -> Vec<Vector<T>> {
+        let (_, pivots) = self.gauss_jordan(self.col_size());
+        let columns = self.transpose();
+        pivots.into_iter().map(|c| columns[c].clone()).collect()
+    }
+
+    /// Return a basis of the row space of this matrix, made of the non-zero rows of the reduced row echelon form.
+    pub fn row_space(&self) -> Vec<Vector<T>> {
+        let (mut rref, pivots) = self.gauss_jordan(self.col_size());
+        rref.rows.truncate(pivots.len());
+        rref.rows
+    }
+
+    /// Return a basis of the left null space (cokernel) of this matrix, the solutions of `x A = 0`.
+    pub fn left_null_space(&self) -> Vec<Vector<T>> {
+        self.transpose().null_space()
+    }
+}
+
+impl Matrix {
+    /// Return a basis of the null space of this matrix, each basis vector scaled to a primitive integer vector.
+    pub fn null_space_primitive(&self) -> Vec<Vector> {
+        self.null_space().iter().map(Vector::primitive).collect()
+    }
+}
+
+impl Vector {
+    /// Scale this vector to a primitive integer vector: integer elements with no common divisor, first non-zero element positive.
+    ///
+    /// A zero vector is returned unchanged.
+    pub fn primitive(&self) -> Self {
+        if self.is_empty() || self.is_zero() {
+            return self.clone();
+        }
+
+        // clear the denominators
+        let mut lcm = Fraction::from(1);
+        for e in &self.elements {
+            lcm = Fraction::lcm(lcm, e.denominator().into());
+        }
+        let v = self * lcm;
+
+        // divide by the common divisor, make the first non-zero element positive
+        let mut gcd = Fraction::new();
+        for e in &v.elements {
+            gcd = Fraction::gcd(gcd, *e);
+        }
+        if v[v.count_leading_zeros()] < 0.into() {
+            gcd = -gcd;
+        }
+        v * (Fraction::from(1) / gcd)
+    }
+}
@@ -1,16 +1,19 @@
 use std::{
     fmt::Display,
-    ops::{Index, IndexMut},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::detail;
+use crate::{
+    detail::{self, forward_binop},
+    Field, MatrixError,
+};
 
 use pyinrs::Fraction;
 
-/// Vector with fractions as elements.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
-pub struct Vector {
-    pub(crate) elements: Vec<Fraction>,
+/// Vector with elements of a field, fractions by default.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Vector<T = Fraction> {
+    pub(crate) elements: Vec<T>,
 }
 
 impl Vector {
@@ -21,7 +24,7 @@ impl Vector {
 
     /// Create an n-dimensional vector with all identical elements.
     pub fn create(n: usize, value: Fraction) -> Self {
-        Self { elements: [value].repeat(n) }
+        Self::filled(n, value)
     }
 
     /// Create an n-dimensional vector with all 0 elements.
@@ -33,6 +36,20 @@ impl Vector {
     pub fn ones(n: usize) -> Self {
         Self::create(n, 1.into())
     }
+}
+
+impl<T: Field> Vector<T> {
+    /// Create an n-dimensional vector with all identical elements, of any element type.
+    pub fn filled(n: usize, value: T) -> Self {
+        Self { elements: vec![value; n] }
+    }
+
+    /// Create an n-dimensional vector whose i-th element is `f(i)`, of any element type.
+    pub fn from_fn(n: usize, f: impl FnMut(usize) -> T) -> Self {
+        Self {
+            elements: (0..n).map(f).collect(),
+        }
+    }
 
     /// Returns the number of elements in the vector.
     pub fn size(&self) -> usize {
@@ -51,16 +68,13 @@ impl Vector {
 
     /// Determine whether two vectors are orthogonal.
     pub fn is_orthogonal(&self, that: &Self) -> bool {
-        detail::check_empty(self.size());
-        detail::check_size(self.size(), that.size());
-
-        (self * that) == 0.into()
+        detail::unwrap(self.try_dot(that)).is_zero()
     }
 
     /// Determine whether two vectors are paralle.
     pub fn is_parallel(&self, that: &Self) -> bool {
-        detail::check_empty(self.size());
-        detail::check_size(self.size(), that.size());
+        detail::unwrap(detail::check_empty(self.size()));
+        detail::unwrap(detail::check_size(self.size(), that.size()));
 
         // zero vector parallel to any vector
         if self.is_zero() || that.is_zero() {
@@ -70,28 +84,17 @@ impl Vector {
         // find the first non-zero element
         let i = self.count_leading_zeros();
         // calc the scale factor
-        let scale = that[i] / self[i];
+        let scale = that[i].clone() / self[i].clone();
         // if equal after scale-up, then parallel
         self * scale == *that
     }
 
-    /// Calculate the norm (abs) of the vector.
-    pub fn norm(&self) -> f64 {
-        detail::check_empty(self.size());
-
-        let mut norm = 0.0;
-        for i in 0..self.size() {
-            norm += f64::from(self.elements[i] * self.elements[i]);
-        }
-        norm.sqrt()
-    }
-
     /// Calculate the number of leading zeros of this vector.
     pub fn count_leading_zeros(&self) -> usize {
-        detail::check_empty(self.size());
+        detail::unwrap(detail::check_empty(self.size()));
 
         let mut lz: usize = 0;
-        while self.elements[lz] == 0.into() {
+        while self.elements[lz].is_zero() {
             lz += 1;
             if lz == self.size() {
                 break;
@@ -102,18 +105,89 @@ impl Vector {
 
     /// Return the cross product of two vectors.
     pub fn cross(a: &Self, b: &Self) -> Self {
+        detail::unwrap(Self::try_cross(a, b))
+    }
+
+    /// Return the cross product of two vectors, or an error if the dimensions are incompatible.
+    pub fn try_cross(a: &Self, b: &Self) -> Result<Self, MatrixError> {
+        let f = |i: usize, j: usize| a[i].clone() * b[j].clone() - a[j].clone() * b[i].clone();
         if a.size() == 2 && b.size() == 2 {
-            Self::from([a[0] * b[1] - a[1] * b[0]])
+            Ok(Self::from([f(0, 1)]))
         } else if a.size() == 3 && b.size() == 3 {
-            return Self::from([a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]);
+            Ok(Self::from([f(1, 2), f(2, 0), f(0, 1)]))
         } else {
-            panic!("Error: Incompatible dimensions for cross product.");
+            Err(MatrixError::IncompatibleCross { lhs: a.size(), rhs: b.size() })
+        }
+    }
+
+    /// Return the dot product of two vectors, or an error if they are empty or the sizes mismatch.
+    pub fn try_dot(&self, that: &Self) -> Result<T, MatrixError> {
+        detail::check_empty(self.size())?;
+        detail::check_size(self.size(), that.size())?;
+
+        let mut result = self.zero_like();
+        for i in 0..self.size() {
+            result += self[i].clone() * that[i].clone();
+        }
+        Ok(result)
+    }
+
+    /// Return the sum of two vectors, or an error if the sizes mismatch.
+    pub fn try_add(&self, that: &Self) -> Result<Self, MatrixError> {
+        detail::check_size(self.size(), that.size())?;
+
+        let mut result = self.clone();
+        for i in 0..result.size() {
+            result[i] += that[i].clone();
+        }
+        Ok(result)
+    }
+
+    /// Return the difference of two vectors, or an error if the sizes mismatch.
+    pub fn try_sub(&self, that: &Self) -> Result<Self, MatrixError> {
+        detail::check_size(self.size(), that.size())?;
+
+        let mut result = self.clone();
+        for i in 0..result.size() {
+            result[i] -= that[i].clone();
+        }
+        Ok(result)
+    }
+
+    /// Return the vector obtained by applying `f` to each element, possibly of another element type.
+    pub fn map<U: Field>(&self, f: impl FnMut(&T) -> U) -> Vector<U> {
+        Vector {
+            elements: self.elements.iter().map(f).collect(),
+        }
+    }
+
+    // Return the additive identity of the same kind as the elements, or `T::zero()` if there are none.
+    pub(crate) fn zero_like(&self) -> T {
+        self.elements.first().map_or_else(T::zero, T::zero_like)
+    }
+}
+
+impl<T: Field + Into<f64>> Vector<T> {
+    /// Calculate the norm (abs) of the vector.
+    pub fn norm(&self) -> f64 {
+        detail::unwrap(detail::check_empty(self.size()));
+
+        let mut norm = 0.0;
+        for i in 0..self.size() {
+            norm += (self.elements[i].clone() * self.elements[i].clone()).into();
         }
+        norm.sqrt()
+    }
+}
+
+impl<T> Default for Vector<T> {
+    fn default() -> Self {
+        Self { elements: Vec::new() }
     }
 }
 
-impl<const N: usize> From<[Fraction; N]> for Vector {
-    fn from(value: [Fraction; N]) -> Self {
+impl<T: Field, const N: usize> From<[T; N]> for Vector<T> {
+    fn from(value: [T; N]) -> Self {
         Self { elements: Vec::from(value) }
     }
 }
@@ -126,8 +200,8 @@ impl<const N: usize> From<[i32; N]> for Vector {
     }
 }
 
-impl From<Vec<Fraction>> for Vector {
-    fn from(value: Vec<Fraction>) -> Self {
+impl<T: Field> From<Vec<T>> for Vector<T> {
+    fn from(value: Vec<T>) -> Self {
         Self { elements: value }
     }
 }
@@ -140,105 +214,168 @@ impl From<Vec<i32>> for Vector {
     }
 }
 
-impl Index<usize> for Vector {
-    type Output = Fraction;
+impl<T> Index<usize> for Vector<T> {
+    type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.elements[index]
     }
 }
 
-impl IndexMut<usize> for Vector {
+impl<T> IndexMut<usize> for Vector<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.elements[index]
     }
 }
 
-impl Display for Vector {
+impl<T: Display> Display for Vector<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "[")?;
 
         // calc the max width of element
         let mut width = 0;
-        for i in 0..self.size() {
-            width = width.max(format!("{}", self[i]).len());
+        for e in &self.elements {
+            width = width.max(format!("{}", e).len());
         }
 
         // align right, fill with space
-        for i in 0..self.size() {
+        for (i, e) in self.elements.iter().enumerate() {
             if i != 0 {
                 write!(f, " ")?;
             }
-            write!(f, "{:>width$}", format!("{}", self[i]))?;
+            write!(f, "{:>width$}", format!("{}", e))?;
         }
 
         write!(f, "]")
     }
 }
 
-auto_ops::impl_op_ex!(+=|a: &mut Vector, b: &Vector| {
-    detail::check_size(a.size(), b.size());
-    for i in 0..a.size() {
-        a[i] += b[i];
-    };
-});
+impl<T: Field> AddAssign<&Vector<T>> for Vector<T> {
+    fn add_assign(&mut self, rhs: &Vector<T>) {
+        detail::unwrap(detail::check_size(self.size(), rhs.size()));
+        for i in 0..self.size() {
+            self[i] += rhs[i].clone();
+        }
+    }
+}
+
+impl<T: Field> AddAssign<Vector<T>> for Vector<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        *self += &rhs;
+    }
+}
+
+impl<T: Field> Add<&Vector<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, rhs: &Vector<T>) -> Self::Output {
+        detail::unwrap(self.try_add(rhs))
+    }
+}
+
+forward_binop!(impl<T> Add, add for Vector<T>, Vector<T> => Vector<T>);
+
+impl<T: Field> SubAssign<&Vector<T>> for Vector<T> {
+    fn sub_assign(&mut self, rhs: &Vector<T>) {
+        detail::unwrap(detail::check_size(self.size(), rhs.size()));
+        for i in 0..self.size() {
+            self[i] -= rhs[i].clone();
+        }
+    }
+}
+
+impl<T: Field> SubAssign<Vector<T>> for Vector<T> {
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        *self -= &rhs;
+    }
+}
+
+impl<T: Field> Sub<&Vector<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: &Vector<T>) -> Self::Output {
+        detail::unwrap(self.try_sub(rhs))
+    }
+}
+
+forward_binop!(impl<T> Sub, sub for Vector<T>, Vector<T> => Vector<T>);
+
+impl<T: Field> MulAssign<T> for Vector<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        for i in 0..self.size() {
+            self[i] *= rhs.clone();
+        }
+    }
+}
+
+impl<T: Field> MulAssign<&T> for Vector<T> {
+    fn mul_assign(&mut self, rhs: &T) {
+        *self *= rhs.clone();
+    }
+}
+
+impl<T: Field> Mul<&T> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &T) -> Self::Output {
+        let mut a = self.clone();
+        a *= rhs;
+        a
+    }
+}
+
+forward_binop!(impl<T> Mul, mul for Vector<T>, T => Vector<T>);
 
-auto_ops::impl_op_ex!(+|a: &Vector, b: &Vector| -> Vector {
-    let mut a = a.clone();
-    a += b;
-    a
-});
+impl<T: Field + From<i32>> MulAssign<i32> for Vector<T> {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self *= T::from(rhs);
+    }
+}
 
-auto_ops::impl_op_ex!(-=|a: &mut Vector, b: &Vector| {
-    detail::check_size(a.size(), b.size());
-    for i in 0..a.size() {
-        a[i] -= b[i];
-    };
-});
+impl<T: Field + From<i32>> Mul<i32> for &Vector<T> {
+    type Output = Vector<T>;
 
-auto_ops::impl_op_ex!(-|a: &Vector, b: &Vector| -> Vector {
-    let mut a = a.clone();
-    a -= b;
-    a
-});
+    fn mul(self, rhs: i32) -> Self::Output {
+        self * T::from(rhs)
+    }
+}
+
+impl<T: Field + From<i32>> Mul<i32> for Vector<T> {
+    type Output = Vector<T>;
 
-auto_ops::impl_op_ex!(*=|a: &mut Vector, b: Fraction| {
-    for i in 0..a.size() {
-        a[i] *= b;
+    fn mul(self, rhs: i32) -> Self::Output {
+        &self * rhs
     }
-});
+}
 
-auto_ops::impl_op_ex_commutative!(*|a: &Vector, b: Fraction| -> Vector {
-    let mut a = a.clone();
-    a *= b;
-    a
-});
+impl<T: Field + From<i32>> Mul<&Vector<T>> for i32 {
+    type Output = Vector<T>;
 
-auto_ops::impl_op_ex!(*=|a: &mut Vector, b: i32| {
-    for i in 0..a.size() {
-        a[i] *= Fraction::from(b);
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        rhs * self
     }
-});
+}
+
+impl<T: Field + From<i32>> Mul<Vector<T>> for i32 {
+    type Output = Vector<T>;
 
-auto_ops::impl_op_ex_commutative!(*|a: &Vector, b: i32| -> Vector {
-    let mut a = a.clone();
-    a *= b;
-    a
-});
+    fn mul(self, rhs: Vector<T>) -> Self::Output {
+        &rhs * self
+    }
+}
 
-auto_ops::impl_op_ex!(*|a: &Vector, b: &Vector| -> Fraction {
-    detail::check_empty(a.size());
-    detail::check_size(a.size(), b.size());
+impl<T: Field> Mul<&Vector<T>> for &Vector<T> {
+    type Output = T;
 
-    let mut result = Fraction::new();
-    for i in 0..a.size() {
-        result += a[i] * b[i];
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        detail::unwrap(self.try_dot(rhs))
     }
-    result
-});
+}
+
+forward_binop!(impl<T> Mul, mul for Vector<T>, Vector<T> => T);
 
-impl IntoIterator for Vector {
-    type Item = Fraction;
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
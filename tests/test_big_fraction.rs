@@ -0,0 +1,113 @@
+#![cfg(feature = "big-fraction")]
+
+use mymatrix::{BigFraction, Int, Matrix, MatrixError, Vector};
+use pyinrs::Fraction;
+use rstest::rstest;
+
+fn hilbert(n: usize) -> Matrix<BigFraction> {
+    Matrix::from_fn(n, n, |i, j| BigFraction::from((1, (i + j + 1) as i64)))
+}
+
+#[rstest]
+fn basics() {
+    assert_eq!(BigFraction::new(), 0.into());
+    assert_eq!(BigFraction::from((6, -4)), BigFraction::from((-3, 2)));
+    assert_eq!(BigFraction::from((6, -4)).numerator(), &Int::from(-3));
+    assert_eq!(BigFraction::from((6, -4)).denominator(), &Int::from(2));
+    assert_eq!(BigFraction::from((-3, 2)).abs(), BigFraction::from((3, 2)));
+    assert!(BigFraction::from((1, 3)) < BigFraction::from((1, 2)));
+    assert!(BigFraction::from((-1, 2)) < BigFraction::from((-1, 3)));
+}
+
+#[rstest]
+fn operations() {
+    let a = BigFraction::from((1, 2));
+    let b = BigFraction::from((1, 3));
+    assert_eq!(&a + &b, BigFraction::from((5, 6)));
+    assert_eq!(&a - &b, BigFraction::from((1, 6)));
+    assert_eq!(&a * &b, BigFraction::from((1, 6)));
+    assert_eq!(&a / &b, BigFraction::from((3, 2)));
+    assert_eq!(-a, BigFraction::from((-1, 2)));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Divide by zero.")]
+fn divide_by_zero() {
+    let _ = BigFraction::from(1) / BigFraction::new();
+}
+
+#[rstest]
+fn format() {
+    assert_eq!(format!("{}", BigFraction::from((-6, 4))), "-3/2");
+    assert_eq!(format!("{}", BigFraction::from(7)), "7");
+    assert_eq!(format!("{}", Matrix::from([[BigFraction::from((1, 2)), BigFraction::from(3)]])), "[\n1/2   3\n]");
+}
+
+#[rstest]
+fn conversion() {
+    assert_eq!(BigFraction::from(Fraction::from((-3, 4))), BigFraction::from((-3, 4)));
+    assert_eq!(Fraction::try_from(BigFraction::from((-3, 4))), Ok(Fraction::from((-3, 4))));
+    assert_eq!(f64::from(BigFraction::from((-3, 4))), -0.75);
+
+    // numerator and denominator beyond the range of f64
+    let ten = |e: i64| Int::pow(&Int::from(10), &Int::from(e));
+    assert_eq!(f64::from(BigFraction::from((ten(400) + Int::from(1), ten(399)))), 10.0);
+    assert_eq!(f64::from(BigFraction::from((-ten(400), ten(399) * Int::from(3)))), -10.0 / 3.0);
+    assert_eq!(f64::from(BigFraction::from((ten(300), ten(700)))), 0.0);
+    assert_eq!(f64::from(BigFraction::from((ten(700), Int::from(7)))), f64::INFINITY);
+    assert_eq!(f64::from(BigFraction::from(ten(308) * Int::from(17) / Int::from(10))), 1.7e308);
+    assert_eq!(f64::from(BigFraction::from((Int::from(1), ten(310)))), 1e-310);
+
+    let big = BigFraction::from(Int::from(i128::MAX) + Int::from(1));
+    assert_eq!(Fraction::try_from(big), Err(MatrixError::Overflow));
+
+    let m = Matrix::from([[1, 2], [3, 4]]);
+    let big = Matrix::<BigFraction>::from(m.clone());
+    assert_eq!(big[1][0], 3.into());
+    assert_eq!(Matrix::try_from(big), Ok(m));
+
+    let v = Vector::from([1, 2, 3]);
+    let big = Vector::<BigFraction>::from(v.clone());
+    assert_eq!(big[2], 3.into());
+    assert_eq!(Vector::try_from(big), Ok(v));
+
+    let big = Matrix::from([[BigFraction::from(Int::from(i128::MAX) * Int::from(2))]]);
+    assert_eq!(Matrix::<Fraction>::try_from(big), Err(MatrixError::Overflow));
+}
+
+#[rstest]
+fn hilbert_inverse() {
+    let h = hilbert(12);
+    let inv = h.inv().unwrap();
+
+    // the inverse of a Hilbert matrix has integer elements
+    assert!((0..12).all(|i| (0..12).all(|j| inv[i][j].denominator() == &Int::from(1))));
+    assert_eq!(inv[0][0], 144.into());
+    assert_eq!(&h * &inv, Matrix::scalar(12, 1.into()));
+    assert_eq!(h.det() * inv.det(), 1.into());
+    assert_eq!(h.rank(), 12);
+}
+
+#[rstest]
+fn large_det() {
+    // Vandermonde matrix with nodes 1..=15, det = prod_{i<j} (j - i) = prod_{k=1}^{14} k!
+    let n = 15;
+    let m = Matrix::from_fn(n, n, |i, j| BigFraction::from(Int::pow(&Int::from(i + 1), &Int::from(j))));
+    let mut expected = Int::from(1);
+    for k in 1..n {
+        expected *= Int::from(k).factorial();
+    }
+    assert_eq!(m.det(), BigFraction::from(expected));
+    assert_eq!(m.rank(), n);
+}
+
+#[rstest]
+fn operators() {
+    let a = Matrix::from([[BigFraction::from(1), BigFraction::from(2)], [BigFraction::from(3), BigFraction::from(4)]]);
+    let b = Matrix::from([[BigFraction::from((1, 2)), BigFraction::from(0)], [BigFraction::from(0), BigFraction::from((1, 2))]]);
+    assert_eq!(&a * &b, BigFraction::from((1, 2)) * &a);
+    assert_eq!(&a + &a, 2 * &a);
+    assert_eq!(&a - &a, Matrix::filled(2, 2, 0.into()));
+    assert_eq!(a.row_canonical_form(), Matrix::scalar(2, 1.into()));
+    assert_eq!(Matrix::<BigFraction>::from(Matrix::from([[1, 2], [2, 4]])).rank(), 1);
+}
@@ -0,0 +1,139 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use mymatrix::{Field, Matrix, Solution, Vector};
+use pyinrs::Fraction;
+use rstest::rstest;
+
+// Integers modulo 7, a user-defined field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Mod7(u32);
+
+impl Add for Mod7 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Mod7((self.0 + rhs.0) % 7)
+    }
+}
+
+impl Sub for Mod7 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Mod7((self.0 + 7 - rhs.0) % 7)
+    }
+}
+
+impl Mul for Mod7 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Mod7(self.0 * rhs.0 % 7)
+    }
+}
+
+impl Div for Mod7 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        assert_ne!(rhs.0, 0);
+        // a^(p-2) is the inverse of a by Fermat's little theorem
+        self * Mod7(rhs.0.pow(5) % 7)
+    }
+}
+
+impl Neg for Mod7 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Mod7((7 - self.0) % 7)
+    }
+}
+
+impl AddAssign for Mod7 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Mod7 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Mod7 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Mod7 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Field for Mod7 {
+    fn zero() -> Self {
+        Mod7(0)
+    }
+
+    fn one() -> Self {
+        Mod7(1)
+    }
+}
+
+fn mod7<const R: usize, const C: usize>(m: [[u32; C]; R]) -> Matrix<Mod7> {
+    Matrix::from(m.map(|row| row.map(Mod7)))
+}
+
+#[rstest]
+fn fraction() {
+    assert_eq!(<Fraction as Field>::zero(), 0.into());
+    assert_eq!(<Fraction as Field>::one(), 1.into());
+    assert!(Fraction::from(0).is_zero());
+    assert_eq!(Matrix::<Fraction>::scalar(2, 1.into()), Matrix::identity(2));
+}
+
+#[rstest]
+fn float() {
+    let a = Matrix::from([[2.0, 1.0], [4.0, 3.0]]);
+    assert_eq!(a.det(), 2.0);
+    assert_eq!(a.rank(), 2);
+    assert_eq!(a.trace(), 5.0);
+    assert_eq!(a.transpose(), Matrix::from([[2.0, 4.0], [1.0, 3.0]]));
+    assert_eq!(a.inv().unwrap(), Matrix::from([[1.5, -0.5], [-2.0, 1.0]]));
+    assert_eq!(a.row_echelon_form(), Matrix::from([[2.0, 1.0], [0.0, 1.0]]));
+    assert_eq!(a.lu_decomposition(), (Matrix::from([[1.0, 0.0], [2.0, 1.0]]), Matrix::from([[2.0, 1.0], [0.0, 1.0]])));
+    assert_eq!(a.solve(&Vector::from([3.0, 7.0])), Solution::Unique(Vector::from([1.0, 1.0])));
+
+    assert_eq!(&a + &a, 2 * &a);
+    assert_eq!(&a - &a, Matrix::filled(2, 2, 0.0));
+    assert_eq!(0.5 * &a, Matrix::from([[1.0, 0.5], [2.0, 1.5]]));
+    assert_eq!(&a * &a.inv().unwrap(), Matrix::scalar(2, 1.0));
+
+    let v = Vector::from([3.0, 4.0]);
+    assert_eq!(v.norm(), 5.0);
+    assert_eq!(&v * &v, 25.0);
+    assert_eq!(2.0 * &v, Vector::from([6.0, 8.0]));
+    assert_eq!(format!("{}", v), "[3 4]");
+}
+
+#[rstest]
+fn user_defined() {
+    let a = mod7([[1, 2, 3], [4, 5, 6], [0, 1, 0]]);
+    assert_eq!(a.det(), Mod7(6));
+    assert_eq!(a.rank(), 3);
+    assert_eq!(&a * &a.inv().unwrap(), Matrix::scalar(3, Mod7(1)));
+    assert_eq!(a.adj(), &a.inv().unwrap() * a.det());
+
+    // [1, 2, 3] + 2 * [4, 5, 6] = [9, 12, 15] = [2, 5, 1] (mod 7), so the rank drops to 2
+    let b = mod7([[1, 2, 3], [4, 5, 6], [2, 5, 1]]);
+    assert_eq!(b.det(), Mod7(0));
+    assert_eq!(b.rank(), 2);
+    assert_eq!(b.inv(), None);
+    assert_eq!(b.null_space().len(), 1);
+    let x = &b.null_space()[0];
+    assert!((0..3).all(|i| (&b[i] * x).is_zero()));
+}
@@ -0,0 +1,211 @@
+use mymatrix::{try_projector, Field, Gf, GfDyn, Matrix, MatrixError, Solution, Vector};
+use pyinrs::Fraction;
+use rstest::rstest;
+
+type F7 = Gf<7>;
+
+fn gf7<const R: usize, const C: usize>(m: [[i64; C]; R]) -> Matrix<F7> {
+    Matrix::from(m.map(|row| row.map(F7::new)))
+}
+
+#[rstest]
+fn basics() {
+    assert_eq!(F7::new(10).value(), 3);
+    assert_eq!(F7::new(-1).value(), 6);
+    assert_eq!(F7::new(3).modulus(), 7);
+    assert_eq!(F7::from(3) + F7::from(5), F7::from(1));
+    assert_eq!(F7::from(3) - F7::from(5), F7::from(5));
+    assert_eq!(F7::from(3) * F7::from(5), F7::from(1));
+    assert_eq!(F7::from(1) / F7::from(3), F7::from(5));
+    assert_eq!(-F7::from(3), F7::from(4));
+    assert_eq!(F7::from(3).inv(), Some(F7::from(5)));
+    assert_eq!(F7::from(0).inv(), None);
+    assert_eq!(F7::from(3).pow(6), F7::one());
+    assert_eq!(format!("{}", gf7([[1, 2], [3, 4]])), "[\n1 2\n3 4\n]");
+
+    // large prime, products do not overflow
+    let p = (1u64 << 61) - 1;
+    assert_eq!(Gf::<{ (1u64 << 61) - 1 }>::from(p - 1) * Gf::from(p - 1), Gf::one());
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Divide by zero.")]
+fn divide_by_zero() {
+    let _ = F7::from(1) / F7::from(0);
+}
+
+#[rstest]
+fn det_rank_inv() {
+    let a = gf7([[1, 2, 3], [4, 5, 6], [0, 1, 0]]);
+    assert_eq!(a.det(), F7::from(6));
+    assert_eq!(a.rank(), 3);
+    assert_eq!(&a * &a.inv().unwrap(), Matrix::scalar(3, F7::one()));
+    assert_eq!(a.row_canonical_form(), Matrix::scalar(3, F7::one()));
+
+    // singular mod 7 but not over the rationals: det = 7
+    let b = Matrix::from([[1, 2], [3, 13]]);
+    assert_eq!(b.det(), 7.into());
+    let b = Matrix::<F7>::try_from(b).unwrap();
+    assert_eq!(b.det(), F7::zero());
+    assert_eq!(b.rank(), 1);
+    assert_eq!(b.inv(), None);
+    assert_eq!(b.row_canonical_form(), gf7([[1, 2], [0, 0]]));
+}
+
+#[rstest]
+fn solve() {
+    let a = gf7([[1, 2], [3, 4]]);
+    assert_eq!(
+        a.solve(&Vector::from([F7::from(5), F7::from(6)])),
+        Solution::Unique(Vector::from([F7::from(3), F7::from(1)]))
+    );
+
+    let b = gf7([[1, 2], [3, 6]]);
+    assert_eq!(b.solve(&Vector::from([F7::from(1), F7::from(1)])), Solution::NoSolution);
+    assert_eq!(
+        b.solve(&Vector::from([F7::from(1), F7::from(3)])),
+        Solution::Infinite {
+            particular: Vector::from([F7::from(1), F7::from(0)]),
+            basis: vec![Vector::from([F7::from(5), F7::from(1)])]
+        }
+    );
+}
+
+#[rstest]
+fn projection() {
+    type F5 = Gf<5>;
+    let v = Vector::from([F5::from(1), F5::from(0)]);
+
+    let u = Vector::from([F5::from(1), F5::from(1)]);
+    assert_eq!(v.project_onto(&u), Vector::from([F5::from(3), F5::from(3)]));
+    assert_eq!(try_projector(&[u]), Ok(Matrix::from([[F5::from(3), F5::from(3)], [F5::from(3), F5::from(3)]])));
+
+    // <u, u> = 1 + 4 = 0 (mod 5) for a non-zero u, so there is no orthogonal projection onto its line
+    let u = Vector::from([F5::from(1), F5::from(2)]);
+    assert_eq!(v.try_project_onto(&u), Err(MatrixError::Singular));
+    assert_eq!(v.try_reject_from(&u), Err(MatrixError::Singular));
+    assert_eq!(v.try_reflect_across(&u), Err(MatrixError::Singular));
+    assert_eq!(try_projector(&[u]), Err(MatrixError::Singular));
+    assert_eq!(
+        Matrix::from([[F5::from(1)], [F5::from(2)]]).try_projection_onto_column_space(),
+        Err(MatrixError::Singular)
+    );
+    let zero = Vector::filled(2, F5::zero());
+    assert_eq!(v.project_onto(&zero), zero);
+}
+
+#[rstest]
+fn conversion() {
+    assert_eq!(F7::try_from(Fraction::from((1, 2))), Ok(F7::from(4)));
+    assert_eq!(F7::try_from(Fraction::from((-3, 5))), Ok(F7::from(5)));
+    assert_eq!(F7::try_from(Fraction::from((1, 14))), Err(MatrixError::NotInvertible { modulus: 7 }));
+
+    let m = Matrix::from([[Fraction::from((1, 2)), Fraction::from(8)]]);
+    assert_eq!(Matrix::<F7>::try_from(m.clone()), Ok(gf7([[4, 1]])));
+    assert_eq!(Vector::<F7>::try_from(m[0].clone()), Ok(Vector::from([F7::from(4), F7::from(1)])));
+    assert_eq!(Matrix::<Gf<2>>::try_from(m.clone()), Err(MatrixError::NotInvertible { modulus: 2 }));
+
+    assert_eq!(m.try_to_gf(7), Ok(Matrix::from([[GfDyn::new(4, 7), GfDyn::new(1, 7)]])));
+    assert_eq!(m[0].try_to_gf(2), Err(MatrixError::NotInvertible { modulus: 2 }));
+}
+
+#[rstest]
+fn runtime_modulus() {
+    let p = 1_000_000_007;
+    let a = Matrix::from([[1, 2], [3, 4]]).try_to_gf(p).unwrap();
+    assert_eq!(a.det(), GfDyn::new(-2, p));
+    assert_eq!(a.det().value(), (p - 2) as i128);
+    assert_eq!(a.det().modulus(), Some(p));
+    assert_eq!(&a * &a.inv().unwrap(), Matrix::scalar(2, GfDyn::new(1, p)));
+    assert_eq!(a.pow(0), Matrix::scalar(2, GfDyn::new(1, p)));
+    assert_eq!(a.rank(), 2);
+    assert_eq!(GfDyn::new(3, 7) / GfDyn::new(5, 7), GfDyn::new(2, 7));
+    assert_eq!(GfDyn::one().modulus(), None);
+    assert_eq!((-GfDyn::one()).value(), -1);
+    assert_ne!(GfDyn::one(), GfDyn::new(1, 7));
+
+    let b = Matrix::from([[1, 2], [3, 13]]).try_to_gf(7).unwrap();
+    assert_eq!(b.det(), GfDyn::new(0, 7));
+    assert_eq!(b.rank(), 1);
+    assert_eq!(b.inv(), None);
+    assert_eq!(
+        b.solve(&Vector::from([GfDyn::new(1, 7), GfDyn::new(3, 7)])),
+        Solution::Infinite {
+            particular: Vector::from([GfDyn::new(1, 7), GfDyn::new(0, 7)]),
+            basis: vec![Vector::from([GfDyn::new(5, 7), GfDyn::new(1, 7)])]
+        }
+    );
+}
+
+#[rstest]
+fn runtime_modulus_large() {
+    let p = 18_446_744_073_709_551_557; // the largest prime below 2^64
+    assert_eq!(GfDyn::new(-1, p) * GfDyn::new(-1, p), GfDyn::new(1, p));
+    assert_eq!(GfDyn::new(-1, p) * GfDyn::one(), GfDyn::new(-1, p));
+    assert_eq!(GfDyn::new(-2, p) / GfDyn::new(-1, p), GfDyn::new(2, p));
+    assert_eq!(Gf::<18_446_744_073_709_551_557>::new(-1) * Gf::new(-1), Gf::new(1));
+
+    let a = Matrix::from([[-1, -2], [-3, -5]]).try_to_gf(p).unwrap();
+    assert_eq!(a.det(), GfDyn::new(-1, p));
+    assert_eq!(a.inv().unwrap(), Matrix::from([[5, -2], [-3, 1]]).try_to_gf(p).unwrap());
+}
+
+#[rstest]
+fn runtime_modulus_lu() {
+    // the zero pivot is allowed, since the element below it is already eliminated
+    let a = Matrix::from([[0, 1], [0, 1]]).try_to_gf(5).unwrap();
+    let (l, u) = a.try_lu_decomposition().unwrap();
+    assert_eq!(l, Matrix::from([[1, 0], [0, 1]]).try_to_gf(5).unwrap());
+    assert_eq!(u, a);
+    assert_eq!(
+        Matrix::from([[0, 1], [1, 1]]).try_to_gf(5).unwrap().try_lu_decomposition(),
+        Err(MatrixError::ZeroPivot { index: 0 })
+    );
+
+    let b = Matrix::from([[2, 1], [4, 3]]).try_to_gf(5).unwrap();
+    let (l, u) = b.lu_decomposition();
+    assert_eq!(l, Matrix::from([[1, 0], [2, 1]]).try_to_gf(5).unwrap());
+    assert_eq!(u, Matrix::from([[2, 1], [0, 1]]).try_to_gf(5).unwrap());
+    assert_eq!(a.trace(), GfDyn::new(1, 5));
+    assert_eq!(&b[0] * &b[1], GfDyn::new(1, 5));
+}
+
+#[rstest]
+fn runtime_modulus_results() {
+    let a = Matrix::from([[1, 2], [2, 4]]).try_to_gf(5).unwrap();
+    let kernel = a.null_space();
+    let gf5 = |v: &Vector<GfDyn>| (0..v.size()).all(|i| v[i].modulus() == Some(5));
+    let modulus = |m: &Matrix<GfDyn>| (0..m.row_size()).all(|i| gf5(&m[i]));
+    assert_eq!(kernel, vec![Vector::from([GfDyn::new(3, 5), GfDyn::new(1, 5)])]);
+    assert!(kernel.iter().all(gf5));
+
+    assert_eq!(a.det().modulus(), Some(5));
+    assert!(modulus(&a.pow(0)));
+    assert!(modulus(&a.plu().p()));
+    assert_eq!(a.plu().sign().modulus(), Some(5));
+    assert!(modulus(&a.ldl().p()));
+    assert!(modulus(&a.ldl().l));
+    assert!(modulus(&Matrix::filled(2, 2, GfDyn::new(0, 5)).projection_onto_column_space()));
+
+    let b = Matrix::from([[1, 2], [3, 4]]).try_to_gf(5).unwrap();
+    assert!(modulus(&b.projection_onto_column_space()));
+    let (l, u) = b.lu_decomposition();
+    assert!(modulus(&l) && modulus(&u));
+    assert!(modulus(&b.inv().unwrap()));
+    match b.solve(&Vector::from([GfDyn::new(1, 5), GfDyn::new(0, 5)])) {
+        Solution::Unique(x) => assert!(gf5(&x)),
+        _ => panic!("b is invertible"),
+    }
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The modulus is not a prime.")]
+fn not_prime() {
+    GfDyn::new(1, 15);
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The moduli mismatch.")]
+fn moduli_mismatch() {
+    let _ = GfDyn::new(1, 5) + GfDyn::new(1, 7);
+}
@@ -1,4 +1,4 @@
-use mymatrix::Matrix;
+use mymatrix::{projector, try_projector, JordanBlock, Ldl, Matrix, MatrixError, Plu, Polynomial, Rref, Solution, Vector};
 use pyinrs::Fraction;
 use rstest::{fixture, rstest};
 
@@ -128,6 +128,47 @@ fn row_canonical_form(setup: Fixture) {
     assert_eq!(Matrix::ones(2, 2).row_canonical_form(), Matrix::from([[1, 1], [0, 0]]));
     assert_eq!(Matrix::from([[1, 2, 3], [4, 5, 6]]).row_canonical_form(), Matrix::from([[1, 0, -1], [0, 1, 2]]));
     assert_eq!(Matrix::from([[1, 2], [3, 4], [5, 6]]).row_canonical_form(), Matrix::from([[1, 0], [0, 1], [0, 0]]));
+
+    // pivots not on the diagonal
+    assert_eq!(Matrix::from([[0, 2, 4], [0, 0, 3]]).row_canonical_form(), Matrix::from([[0, 1, 0], [0, 0, 1]]));
+    assert_eq!(
+        Matrix::from([[1, 2, 3, 4], [2, 4, 7, 9]]).row_canonical_form(),
+        Matrix::from([[1, 2, 0, 1], [0, 0, 1, 1]])
+    );
+    assert_eq!(
+        Matrix::from([[0, 0, 1], [0, 1, 0], [0, 0, 0]]).row_canonical_form(),
+        Matrix::from([[0, 1, 0], [0, 0, 1], [0, 0, 0]])
+    );
+}
+
+#[rstest]
+fn rref(setup: Fixture) {
+    assert_eq!(
+        setup.mat_0x0.rref(),
+        Rref {
+            matrix: Matrix::new(),
+            pivots: vec![],
+            free: vec![]
+        }
+    );
+    assert_eq!(
+        setup.mat_3x3.rref(),
+        Rref {
+            matrix: Matrix::from([[1, 0, -1], [0, 1, 2], [0, 0, 0]]),
+            pivots: vec![0, 1],
+            free: vec![2]
+        }
+    );
+
+    let rref = Matrix::from([[0, 2, 4, 2], [0, 0, 3, 3], [0, 4, 8, 4]]).rref();
+    assert_eq!(rref.matrix, Matrix::from([[0, 1, 0, -1], [0, 0, 1, 1], [0, 0, 0, 0]]));
+    assert_eq!(rref.pivots, vec![1, 2]);
+    assert_eq!(rref.free, vec![0, 3]);
+    assert_eq!(rref.rank(), 2);
+
+    assert_eq!(Matrix::zeros(2, 3).rref().pivots, vec![]);
+    assert_eq!(Matrix::zeros(2, 3).rref().free, vec![0, 1, 2]);
+    assert_eq!(Matrix::identity(3).rref().rank(), 3);
 }
 
 #[rstest]
@@ -139,6 +180,49 @@ fn det(setup: Fixture) {
     assert_eq!(Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 0]]).det(), 27.into());
 }
 
+#[rstest]
+fn det_fraction_free() {
+    // Vandermonde matrix of nodes 1..=8, det = 1! * 2! * ... * 7!
+    let vandermonde = Matrix::from((1..=8).map(|x: i128| (0..8).map(|k| Fraction::from(x.pow(k))).collect()).collect::<Vec<Vec<_>>>());
+    let expected = (1..8).map(|k| (1..=k).product::<i128>()).product::<i128>();
+    assert_eq!(vandermonde.det(), expected.into());
+    assert_eq!(vandermonde.rank(), 8);
+
+    // rational matrix
+    let a = Matrix::from([[Fraction::from((1, 2)), Fraction::from((1, 3))], [Fraction::from((1, 4)), Fraction::from((1, 5))]]);
+    assert_eq!(a.det(), Fraction::from((1, 60)));
+
+    // row swaps
+    assert_eq!(Matrix::from([[0, 1], [1, 0]]).det(), (-1).into());
+    assert_eq!(Matrix::from([[0, 0, 2], [0, 3, 0], [5, 0, 0]]).det(), (-30).into());
+    assert_eq!(Matrix::from([[0, 1, 0], [0, 0, 1], [1, 0, 0]]).det(), 1.into());
+}
+
+#[rstest]
+fn fraction_free_form(setup: Fixture) {
+    assert_eq!(setup.mat_0x0.fraction_free_form(), Matrix::new());
+    assert_eq!(setup.mat_1x1.fraction_free_form(), Matrix::from([[2]]));
+    assert_eq!(setup.mat_3x3.fraction_free_form(), Matrix::from([[1, 2, 3], [0, -3, -6], [0, 0, 0]]));
+
+    // the last pivot of a square matrix is its determinant
+    assert_eq!(
+        Matrix::from([[2, 3, 1], [4, 7, 1], [6, 7, 3]]).fraction_free_form(),
+        Matrix::from([[2, 3, 1], [0, 2, -2], [0, 0, -4]])
+    );
+
+    // pivots skip columns, zero rows last
+    assert_eq!(
+        Matrix::from([[0, 2, 4], [0, 1, 2], [0, 3, 5]]).fraction_free_form(),
+        Matrix::from([[0, 2, 4], [0, 0, -2], [0, 0, 0]])
+    );
+
+    // scaled by the common denominator
+    assert_eq!(
+        Matrix::from([[Fraction::from((1, 2)), Fraction::from((1, 3))], [Fraction::from((1, 4)), Fraction::from((1, 5))]]).fraction_free_form(),
+        Matrix::from([[30, 20], [0, 60]])
+    );
+}
+
 #[rstest]
 fn submatrix(setup: Fixture) {
     assert_eq!(setup.mat_3x3.submatrix(0, 0), Matrix::from([[5, 6], [8, 9]]));
@@ -217,6 +301,1101 @@ fn lu_decomposition(setup: Fixture) {
         setup.mat_3x3.lu_decomposition(),
         (Matrix::from([[1, 0, 0], [4, 1, 0], [7, 2, 1]]), Matrix::from([[1, 2, 3], [0, -3, -6], [0, 0, 0]]))
     );
+
+    // triangular matrices
+    let upper = Matrix::from([[1, 2], [0, 3]]);
+    assert_eq!(upper.lu_decomposition(), (Matrix::identity(2), upper.clone()));
+    assert_eq!(
+        Matrix::from([[0, 1], [0, 1]]).lu_decomposition(),
+        (Matrix::identity(2), Matrix::from([[0, 1], [0, 1]]))
+    );
+    assert_eq!(
+        Matrix::from([[2, 0], [4, 3]]).lu_decomposition(),
+        (Matrix::from([[1, 0], [2, 1]]), Matrix::from([[2, 0], [0, 3]]))
+    );
+}
+
+#[rstest]
+fn char_poly(setup: Fixture) {
+    assert_eq!(setup.mat_0x0.char_poly(), Polynomial::from([1]));
+    assert_eq!(setup.mat_1x1.char_poly(), Polynomial::from([-2, 1]));
+    assert_eq!(setup.mat_3x3.char_poly(), Polynomial::from([0, -18, -15, 1]));
+    assert_eq!(Matrix::from([[2, 0, 0], [0, 3, 4], [0, 4, 9]]).char_poly(), Polynomial::from([-22, 35, -14, 1]));
+
+    // consistent with trace and det
+    let mut a = Matrix::from([[2, -1, 0, 3], [1, 0, 5, 4], [5, 6, 7, 8], [0, 0, 1, -2]]);
+    a[1][1] = Fraction::from((1, 2));
+    let p = a.char_poly();
+    assert_eq!(p.degree(), Some(4));
+    assert!(p.is_monic());
+    assert_eq!(p.coeff(3), -a.trace());
+    assert_eq!(p.coeff(0), a.det());
+
+    // Cayley-Hamilton: p(A) = 0, and p(0) = det(-A)
+    let mut pa = Matrix::zeros(4, 4);
+    for &c in p.coeffs().iter().rev() {
+        pa = pa * a.clone() + Matrix::identity(4) * c;
+    }
+    assert_eq!(pa, Matrix::zeros(4, 4));
+    assert_eq!(p.eval(0.into()), (a.clone() * -1).det());
+
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_char_poly(), Err(MatrixError::NotSquare { shape: (1, 3) }));
+}
+
+#[rstest]
+fn rational_eigenvalues(setup: Fixture) {
+    assert_eq!(setup.mat_0x0.rational_eigenvalues(), vec![]);
+    assert_eq!(setup.mat_1x1.rational_eigenvalues(), vec![(2.into(), 1)]);
+    // x^3 - 15x^2 - 18x = x (x^2 - 15x - 18), irrational roots are not returned
+    assert_eq!(setup.mat_3x3.rational_eigenvalues(), vec![(0.into(), 1)]);
+    assert_eq!(
+        Matrix::from([[2, 0, 0], [0, 3, 4], [0, 4, 9]]).rational_eigenvalues(),
+        vec![(1.into(), 1), (2.into(), 1), (11.into(), 1)]
+    );
+    assert_eq!(
+        Matrix::from([[2, 1, 0], [0, 2, 0], [0, 0, -1]]).rational_eigenvalues(),
+        vec![((-1).into(), 1), (2.into(), 2)]
+    );
+    assert_eq!(
+        Matrix::from([[Fraction::from((1, 2)), 1.into()], [0.into(), Fraction::from((-1, 3))]]).rational_eigenvalues(),
+        vec![(Fraction::from((-1, 3)), 1), (Fraction::from((1, 2)), 1)]
+    );
+    assert_eq!(Matrix::from([[0, -1], [1, 0]]).rational_eigenvalues(), vec![]);
+
+    // the companion matrix of x^2 + n has no real eigenvalues, the one of x^2 - n takes too many trial divisions
+    let n = 4294967311i128 * 4294967357;
+    let mut a = Matrix::from([[0, 0], [1, 0]]);
+    a[0][1] = Fraction::from(-n);
+    assert_eq!(a.try_rational_eigenvalues(), Ok(vec![]));
+    assert_eq!(a.try_diagonalize(), Err(MatrixError::NonRationalEigenvalue));
+    a[0][1] = Fraction::from(n);
+    assert_eq!(a.try_rational_eigenvalues(), Err(MatrixError::FactorizationLimit { value: -n }));
+    assert_eq!(a.try_diagonalize(), Err(MatrixError::FactorizationLimit { value: -n }));
+    assert!(!a.is_diagonalizable());
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_rational_eigenvalues(), Err(MatrixError::NotSquare { shape: (1, 3) }));
+}
+
+#[rstest]
+fn eigenvectors(setup: Fixture) {
+    let a = Matrix::from([[2, 0, 0], [0, 3, 4], [0, 4, 9]]);
+    for (lambda, _) in a.rational_eigenvalues() {
+        let vectors = a.eigenvectors(lambda);
+        assert_eq!(vectors.len(), 1);
+        let v = &vectors[0];
+        assert!((0..3).all(|i| &a[i] * v == v[i] * lambda)); // A v = λ v
+    }
+    assert_eq!(a.eigenvectors(11.into()), vec![Vector::from([0.into(), Fraction::from((1, 2)), 1.into()])]);
+    assert_eq!(a.eigenvectors(5.into()), vec![]);
+
+    // geometric multiplicity 1 and 2 for the double eigenvalue 2
+    assert_eq!(Matrix::from([[2, 1, 0], [0, 2, 0], [0, 0, -1]]).eigenvectors(2.into()), vec![Vector::from([1, 0, 0])]);
+    assert_eq!(Matrix::identity(2).eigenvectors(1.into()), vec![Vector::from([1, 0]), Vector::from([0, 1])]);
+    assert_eq!(setup.mat_3x3.eigenvectors(0.into()), vec![Vector::from([1, -2, 1])]);
+
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_eigenvectors(1.into()), Err(MatrixError::NotSquare { shape: (1, 3) }));
+}
+
+#[rstest]
+fn diagonalize(setup: Fixture) {
+    let a = Matrix::from([[4, 1], [2, 3]]);
+    let (p, d) = a.diagonalize().unwrap();
+    assert_eq!(d, Matrix::from([[2, 0], [0, 5]]));
+    assert_eq!(&p * &d * p.inv().unwrap(), a);
+
+    // repeated eigenvalue with a full eigenspace
+    let a = Matrix::from([[2, 0, 0], [1, 2, 1], [-1, 0, 1]]);
+    let (p, d) = a.diagonalize().unwrap();
+    assert_eq!(d, Matrix::from([[1, 0, 0], [0, 2, 0], [0, 0, 2]]));
+    assert_eq!(&p * &d * p.inv().unwrap(), a);
+
+    assert_eq!(setup.mat_0x0.diagonalize(), Some((Matrix::new(), Matrix::new())));
+    assert_eq!(setup.mat_1x1.diagonalize(), Some((Matrix::identity(1), setup.mat_1x1.clone())));
+    assert_eq!(setup.mat_3x3.diagonalize(), None);
+    assert_eq!(setup.mat_3x3.try_diagonalize(), Err(MatrixError::NonRationalEigenvalue));
+    assert_eq!(Matrix::from([[0, -1], [1, 0]]).try_diagonalize(), Err(MatrixError::NonRationalEigenvalue));
+    assert_eq!(
+        Matrix::from([[2, 1, 0], [0, 2, 0], [0, 0, -1]]).try_diagonalize(),
+        Err(MatrixError::Defective {
+            eigenvalue: 2.into(),
+            algebraic: 2,
+            geometric: 1
+        })
+    );
+
+    // the product of the eigenvalues has two prime factors above 2^20
+    let a = Matrix::from([[1048583, 0], [1, 1048601]]);
+    let (p, d) = a.diagonalize().unwrap();
+    assert_eq!(d, Matrix::from([[1048583, 0], [0, 1048601]]));
+    assert_eq!(&p * &d * p.inv().unwrap(), a);
+
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_diagonalize(), Err(MatrixError::NotSquare { shape: (1, 3) }));
+}
+
+#[rstest]
+fn is_diagonalizable(setup: Fixture) {
+    assert!(setup.mat_0x0.is_diagonalizable());
+    assert!(setup.mat_1x1.is_diagonalizable());
+    assert!(!setup.mat_3x3.is_diagonalizable());
+    assert!(Matrix::from([[4, 1], [2, 3]]).is_diagonalizable());
+    assert!(Matrix::from([[2, 0, 0], [1, 2, 1], [-1, 0, 1]]).is_diagonalizable());
+    assert!(!Matrix::from([[2, 1, 0], [0, 2, 0], [0, 0, -1]]).is_diagonalizable());
+    assert!(!Matrix::from([[0, -1], [1, 0]]).is_diagonalizable());
+    assert!(Matrix::identity(3).is_diagonalizable());
+
+    // the companion matrix of x^4 + 2^40
+    let mut a = Matrix::from([[0, 0, 0, 0], [1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0]]);
+    a[0][3] = Fraction::from(-(1i64 << 40));
+    assert!(!a.is_diagonalizable());
+    assert_eq!(a.try_diagonalize(), Err(MatrixError::NonRationalEigenvalue));
+    assert!(!Matrix::from([[1, 2, 3]]).is_diagonalizable());
+}
+
+#[rstest]
+fn jordan_form(setup: Fixture) {
+    let block = |eigenvalue: i32, size| JordanBlock {
+        eigenvalue: eigenvalue.into(),
+        size,
+    };
+
+    let a = Matrix::from([[2, 1, 0], [0, 2, 0], [0, 0, -1]]);
+    let jordan = a.jordan_form().unwrap();
+    assert_eq!(jordan.j, Matrix::from([[-1, 0, 0], [0, 2, 1], [0, 0, 2]]));
+    assert_eq!(jordan.blocks, vec![block(-1, 1), block(2, 2)]);
+    assert_eq!(&jordan.p * &jordan.j * jordan.p.inv().unwrap(), a);
+
+    // similar to J = diag(J3(1), J1(1), J2(-2)) by a unimodular P0
+    let j0 = Matrix::from([
+        [1, 1, 0, 0, 0, 0],
+        [0, 1, 1, 0, 0, 0],
+        [0, 0, 1, 0, 0, 0],
+        [0, 0, 0, 1, 0, 0],
+        [0, 0, 0, 0, -2, 1],
+        [0, 0, 0, 0, 0, -2],
+    ]);
+    let p0 = Matrix::from([
+        [1, 2, 0, 1, 0, 3],
+        [0, 1, 1, 0, 2, 0],
+        [1, 0, 1, 1, 0, 0],
+        [0, 0, 0, 1, 1, 0],
+        [2, 1, 0, 0, 1, 0],
+        [0, 1, 0, 0, 0, 1],
+    ]);
+    let a = &p0 * &j0 * p0.inv().unwrap();
+    let jordan = a.jordan_form().unwrap();
+    assert_eq!(jordan.blocks, vec![block(-2, 2), block(1, 3), block(1, 1)]);
+    assert_eq!(jordan.block_sizes(1.into()), vec![3, 1]);
+    assert_eq!(jordan.block_sizes(0.into()), vec![]);
+    assert_eq!(&jordan.p * &jordan.j * jordan.p.inv().unwrap(), a);
+
+    // nilpotent
+    let a = Matrix::from([[0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 0], [0, 0, 0, 0]]);
+    let jordan = a.jordan_form().unwrap();
+    assert_eq!(jordan.blocks, vec![block(0, 3), block(0, 1)]);
+    assert_eq!(jordan.j, a);
+    assert_eq!(&jordan.p * &jordan.j * jordan.p.inv().unwrap(), a);
+
+    // diagonalizable
+    let jordan = Matrix::identity(2).jordan_form().unwrap();
+    assert_eq!((jordan.p, jordan.j), (Matrix::identity(2), Matrix::identity(2)));
+
+    assert_eq!(setup.mat_0x0.jordan_form().unwrap().blocks, vec![]);
+    assert_eq!(setup.mat_3x3.jordan_form(), None);
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_jordan_form(), Err(MatrixError::NotSquare { shape: (1, 3) }));
+}
+
+#[rstest]
+fn frobenius_form(setup: Fixture) {
+    // invariant factors x - 2, (x - 2)(x + 1)
+    let a = Matrix::from([[2, 0, 0], [0, 2, 0], [0, 0, -1]]);
+    let frobenius = a.frobenius_form();
+    assert_eq!(frobenius.invariant_factors, vec![Polynomial::from([-2, 1]), Polynomial::from([-2, -1, 1])]);
+    assert_eq!(frobenius.f, Matrix::from([[2, 0, 0], [0, 0, 2], [0, 1, 1]]));
+    assert_eq!(frobenius.p, Matrix::from([[0, 1, 2], [1, 1, 2], [0, 1, -1]]));
+    assert_eq!(&frobenius.p * &frobenius.f * frobenius.p.inv().unwrap(), a);
+
+    // cyclic: the companion matrix of the characteristic polynomial
+    let frobenius = setup.mat_3x3.frobenius_form();
+    assert_eq!(frobenius.invariant_factors, vec![setup.mat_3x3.char_poly()]);
+    assert_eq!(frobenius.f, Matrix::from([[0, 0, 0], [1, 0, 18], [0, 1, 15]]));
+    let a = Matrix::from([[1, 2, 0, 1], [3, -1, 2, 0], [0, 1, 1, 4], [2, 0, -3, 1]]);
+    assert_eq!(a.frobenius_form().f, Matrix::from([[0, 0, 0, 110], [1, 0, 0, -18], [0, 1, 0, -2], [0, 0, 1, 2]]));
+
+    // non-rational eigenvalues: x^2 + 1 twice
+    let a = Matrix::from([[0, -1, 0, 0], [1, 0, 0, 0], [0, 0, 1, 2], [0, 0, -1, -1]]);
+    let frobenius = a.frobenius_form();
+    assert_eq!(frobenius.invariant_factors, vec![Polynomial::from([1, 0, 1]); 2]);
+    assert_eq!(frobenius.f, Matrix::from([[0, -1, 0, 0], [1, 0, 0, 0], [0, 0, 0, -1], [0, 0, 1, 0]]));
+    assert_eq!(frobenius.p, Matrix::from([[0, 0, 1, 0], [0, 0, 0, 1], [1, 1, 0, 0], [0, -1, 0, 0]]));
+    assert_eq!(&frobenius.p * &frobenius.f * frobenius.p.inv().unwrap(), a);
+
+    // nilpotent: invariant factors x, x^3
+    let frobenius = Matrix::from([[0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 0], [0, 0, 0, 0]]).frobenius_form();
+    assert_eq!(frobenius.invariant_factors, vec![Polynomial::from([0, 1]), Polynomial::from([0, 0, 0, 1])]);
+    assert_eq!(frobenius.f, Matrix::from([[0, 0, 0, 0], [0, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0]]));
+    assert_eq!(frobenius.p, Matrix::from([[0, 0, 0, 1], [0, 0, 1, 0], [0, 1, 0, 0], [1, 0, 0, 0]]));
+
+    // 5x5, the coefficients do not overflow: cyclic
+    let a = Matrix::from([[6, 7, 7, -7, 9], [9, -5, -2, 6, -2], [0, 4, -5, 4, -3], [0, 8, -4, -9, 4], [-3, 0, 7, -5, -9]]);
+    let frobenius = a.frobenius_form();
+    assert_eq!(frobenius.invariant_factors, vec![Polynomial::from([-111054, -18781, -1157, 99, 22, 1])]);
+    assert_eq!(
+        frobenius.f,
+        Matrix::from([[0, 0, 0, 0, 111054], [1, 0, 0, 0, 18781], [0, 1, 0, 0, 1157], [0, 0, 1, 0, -99], [0, 0, 0, 1, -22]])
+    );
+    assert_eq!(&frobenius.p * &frobenius.f * frobenius.p.inv().unwrap(), a);
+    // invariant factors x - 2, (x - 2)^2 (x^2 + 1)
+    let a = Matrix::from([[0, -5, 6, 7, -6], [-6, 11, -4, -3, 0], [-3, 8, -3, -5, 2], [-7, 10, -4, -1, -1], [-3, 3, 0, 0, -1]]);
+    let frobenius = a.frobenius_form();
+    assert_eq!(frobenius.invariant_factors, vec![Polynomial::from([-2, 1]), Polynomial::from([4, -4, 5, -4, 1])]);
+    assert_eq!(&frobenius.p * &frobenius.f * frobenius.p.inv().unwrap(), a);
+
+    assert_eq!(Matrix::identity(3).frobenius_form().invariant_factors, vec![Polynomial::from([-1, 1]); 3]);
+    assert_eq!(Matrix::zeros(2, 2).frobenius_form().invariant_factors, vec![Polynomial::from([0, 1]); 2]);
+
+    assert_eq!(setup.mat_0x0.frobenius_form().invariant_factors, vec![]);
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_frobenius_form(), Err(MatrixError::NotSquare { shape: (1, 3) }));
+}
+
+#[rstest]
+fn min_poly(setup: Fixture) {
+    let a = Matrix::from([[2, 1, 0, 0], [0, 2, 0, 0], [0, 0, 2, 0], [0, 0, 0, -1]]);
+    let p = a.min_poly();
+    assert_eq!(p, Polynomial::from([-2, 1]) * Polynomial::from([-2, 1]) * Polynomial::from([1, 1]));
+    assert_eq!(p.eval_matrix(&a), Matrix::zeros(4, 4));
+    assert_eq!(&a.char_poly() % &p, Polynomial::new());
+
+    assert_eq!(setup.mat_3x3.min_poly(), setup.mat_3x3.char_poly());
+    assert_eq!(Matrix::identity(3).min_poly(), Polynomial::from([-1, 1]));
+    assert_eq!(Matrix::zeros(2, 2).min_poly(), Polynomial::from([0, 1]));
+    assert_eq!(setup.mat_0x0.min_poly(), Polynomial::from([1]));
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_min_poly(), Err(MatrixError::NotSquare { shape: (1, 3) }));
+
+    // 5x5, the coefficients do not overflow: cyclic, and (x - 1)^2 (x^2 + 1) of degree 4
+    let a = Matrix::from([[6, 7, 7, -7, 9], [9, -5, -2, 6, -2], [0, 4, -5, 4, -3], [0, 8, -4, -9, 4], [-3, 0, 7, -5, -9]]);
+    let p = a.min_poly();
+    assert_eq!(p, Polynomial::from([-111054, -18781, -1157, 99, 22, 1]));
+    assert_eq!(p, a.char_poly());
+    assert_eq!(p.eval_matrix(&a), Matrix::zeros(5, 5));
+    let a = Matrix::from([[1, 1, 0, 0, 0], [0, 1, 0, 0, 0], [0, 0, 1, 0, 0], [0, 0, 0, 0, -1], [0, 0, 0, 1, 0]]);
+    let p = a.min_poly();
+    assert_eq!(p, Polynomial::from([1, -2, 2, -2, 1]));
+    assert_eq!(p.eval_matrix(&a), Matrix::zeros(5, 5));
+
+    // diagonalizable over the rationals iff the minimal polynomial is square-free with rational roots
+    for a in [a, Matrix::from([[4, 1], [2, 3]]), Matrix::from([[0, -1], [1, 0]]), setup.mat_3x3] {
+        let p = a.min_poly();
+        let rational = p.rational_roots().iter().map(|&(_, m)| m).sum::<usize>() == p.degree().unwrap();
+        assert_eq!(a.is_diagonalizable(), rational && p.square_free() == p);
+    }
+}
+
+#[rstest]
+fn is_nilpotent(setup: Fixture) {
+    assert!(Matrix::from([[0, 1, 0], [0, 0, 1], [0, 0, 0]]).is_nilpotent());
+    assert!(Matrix::from([[2, -4], [1, -2]]).is_nilpotent());
+    assert!(Matrix::zeros(2, 2).is_nilpotent());
+    assert!(setup.mat_0x0.is_nilpotent());
+    assert!(!Matrix::from([[0, 1], [1, 0]]).is_nilpotent());
+    assert!(!setup.mat_3x3.is_nilpotent());
+    assert!(!Matrix::from([[1, 2, 3]]).is_nilpotent());
+}
+
+#[rstest]
+fn is_idempotent(setup: Fixture) {
+    // projection onto the x axis along y = x
+    let p = Matrix::from([[1, -1], [0, 0]]);
+    assert!(p.is_idempotent());
+    assert_eq!(&p * &p, p);
+    assert!(Matrix::identity(3).is_idempotent());
+    assert!(Matrix::zeros(3, 3).is_idempotent());
+    assert!(setup.mat_0x0.is_idempotent());
+    assert!(!Matrix::from([[1, 1], [0, 1]]).is_idempotent());
+    assert!(!setup.mat_3x3.is_idempotent());
+    assert!(!Matrix::from([[1, 2, 3]]).is_idempotent());
+}
+
+#[rstest]
+fn is_similar(setup: Fixture) {
+    let p = Matrix::from([[1, 2, 0], [0, 1, 1], [1, 0, 1]]);
+    let a = Matrix::from([[2, 1, 0], [0, 2, 0], [0, 0, -1]]);
+    assert!(a.is_similar(&(&p * &a * p.inv().unwrap())));
+    assert!(setup.mat_3x3.is_similar(&setup.mat_3x3.transpose()));
+    let b = Matrix::from([[6, 7, 7, -7, 9], [9, -5, -2, 6, -2], [0, 4, -5, 4, -3], [0, 8, -4, -9, 4], [-3, 0, 7, -5, -9]]);
+    assert!(b.is_similar(&b.transpose()));
+    let b = Matrix::from([[0, -5, 6, 7, -6], [-6, 11, -4, -3, 0], [-3, 8, -3, -5, 2], [-7, 10, -4, -1, -1], [-3, 3, 0, 0, -1]]);
+    assert!(b.is_similar(&Matrix::from([[2, 1, 0, 0, 0], [0, 2, 0, 0, 0], [0, 0, 2, 0, 0], [0, 0, 0, 0, -1], [0, 0, 0, 1, 0]])));
+    assert!(!b.is_similar(&Matrix::from([[2, 0, 0, 0, 0], [0, 2, 0, 0, 0], [0, 0, 2, 0, 0], [0, 0, 0, 0, -1], [0, 0, 0, 1, 0]])));
+
+    // same characteristic polynomial and minimal polynomial, different Jordan blocks
+    let j1 = Matrix::from([[0, 1, 0, 0], [0, 0, 0, 0], [0, 0, 0, 1], [0, 0, 0, 0]]);
+    let j2 = Matrix::from([[0, 1, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]]);
+    assert!(!j1.is_similar(&j2));
+    assert!(!a.is_similar(&Matrix::from([[2, 0, 0], [0, 2, 0], [0, 0, -1]])));
+    assert!(!a.is_similar(&Matrix::identity(2)));
+    assert!(setup.mat_0x0.is_similar(&setup.mat_0x0));
+    assert!(!Matrix::from([[1, 2, 3]]).is_similar(&Matrix::from([[1, 2, 3]])));
+    assert!(!Matrix::from([[1, 2, 3]]).is_similar(&Matrix::identity(1)));
+    assert!(!setup.mat_1x1.is_similar(&setup.mat_3x3));
+}
+
+#[rstest]
+fn pow(setup: Fixture) {
+    // Fibonacci numbers: [[1, 1], [1, 0]]^n = [[F(n+1), F(n)], [F(n), F(n-1)]]
+    let fib = Matrix::from([[1, 1], [1, 0]]);
+    assert_eq!(fib.pow(0), Matrix::identity(2));
+    assert_eq!(fib.pow(1), fib);
+    assert_eq!(fib.pow(10), Matrix::from([[89, 55], [55, 34]]));
+    assert_eq!(fib.pow(-1), fib.inv().unwrap());
+    assert_eq!(fib.pow(-10) * fib.pow(10), Matrix::identity(2));
+    assert_eq!(
+        setup.mat_3x3.pow(5),
+        &setup.mat_3x3 * &setup.mat_3x3 * &setup.mat_3x3 * &setup.mat_3x3 * &setup.mat_3x3
+    );
+
+    // two-state Markov chain, n-step transitions
+    let markov = Matrix::from([[Fraction::from((1, 2)), Fraction::from((1, 2))], [0.into(), 1.into()]]);
+    assert_eq!(markov.pow(3), Matrix::from([[Fraction::from((1, 8)), Fraction::from((7, 8))], [0.into(), 1.into()]]));
+
+    assert_eq!(setup.mat_0x0.pow(-3), setup.mat_0x0);
+    assert_eq!(setup.mat_3x3.pow(0), Matrix::identity(3));
+    assert_eq!(setup.mat_3x3.try_pow(-1), Err(MatrixError::Singular));
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_pow(2), Err(MatrixError::NotSquare { shape: (1, 3) }));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The matrix is singular.")]
+fn bad_pow(setup: Fixture) {
+    setup.mat_3x3.pow(-2);
+}
+
+#[rstest]
+fn pow_apply(setup: Fixture) {
+    let fib = Matrix::from([[1, 1], [1, 0]]);
+    assert_eq!(
+        fib.pow_apply(50, &Vector::from([1, 0])),
+        Vector::from(vec![Fraction::from(20365011074i64), Fraction::from(12586269025i64)])
+    );
+    assert_eq!(fib.pow_apply(-5, &Vector::from([8, 5])), Vector::from([1, 0]));
+    assert_eq!(fib.pow_apply(0, &Vector::from([3, 4])), Vector::from([3, 4]));
+
+    let v = Vector::from([1, -1, 2]);
+    let p = setup.mat_3x3.pow(7);
+    assert_eq!(setup.mat_3x3.pow_apply(7, &v), Vector::from((0..3).map(|i| &p[i] * &v).collect::<Vec<_>>()));
+
+    // the vector-side entry point, by repeated products for a small exponent and by squares for a large one
+    assert_eq!(v.apply_pow(&setup.mat_3x3, 7), setup.mat_3x3.pow_apply(7, &v));
+    let p = setup.mat_3x3.pow(20);
+    assert_eq!(v.apply_pow(&setup.mat_3x3, 20), Vector::from((0..3).map(|i| &p[i] * &v).collect::<Vec<_>>()));
+    assert_eq!(Vector::from([1, 0]).apply_pow(&fib, -1), Vector::from([0, 1]));
+    assert_eq!(v.try_apply_pow(&setup.mat_3x3, -1), Err(MatrixError::Singular));
+
+    assert_eq!(setup.mat_3x3.try_pow_apply(-1, &v), Err(MatrixError::Singular));
+    assert_eq!(
+        setup.mat_3x3.try_pow_apply(2, &Vector::from([1, 2])),
+        Err(MatrixError::SizeMismatch { lhs: 3, rhs: 2 })
+    );
+}
+
+#[rstest]
+fn pow_mod(setup: Fixture) {
+    // F(90) = 2880067194370816120 mod 1000000007
+    let fib = Matrix::from([[1, 1], [1, 0]]);
+    assert_eq!(fib.pow_mod(90, 1_000_000_007)[0][1], Fraction::from(2880067194370816120i64 % 1_000_000_007));
+    assert_eq!(fib.pow_mod(0, 10), Matrix::identity(2));
+    assert_eq!(fib.pow_mod(5, 1), Matrix::zeros(2, 2));
+
+    // large exponents and a modulus close to 2^64 do not overflow
+    let m = u64::MAX - 58; // a prime
+    let a = Matrix::from([[-1, 0], [0, 2]]);
+    // a^(m-1) = I by Fermat's little theorem, so a^(m+58) = a^59
+    assert_eq!(
+        a.pow_mod(u64::MAX, m),
+        Matrix::from(vec![vec![Fraction::from((m - 1) as i128), 0.into()], vec![0.into(), Fraction::from(1i64 << 59)]])
+    );
+    assert_eq!(a.pow_mod(m - 1, m), Matrix::identity(2));
+
+    assert_eq!(setup.mat_3x3.pow_mod(3, 7), setup.mat_3x3.pow(3).pow_mod(1, 7));
+    assert_eq!(
+        Matrix::from([[Fraction::from((1, 2))]]).try_pow_mod(2, 4),
+        Err(MatrixError::NotInvertible { modulus: 4 })
+    );
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_pow_mod(2, 7), Err(MatrixError::NotSquare { shape: (1, 3) }));
+    assert_eq!(fib.try_pow_mod(2, 0), Err(MatrixError::ZeroModulus));
+}
+
+#[rstest]
+fn is_integer(setup: Fixture) {
+    assert!(setup.mat_3x3.is_integer());
+    assert!(setup.mat_0x0.is_integer());
+    assert!(Matrix::from([[Fraction::from((4, 2)), (-3).into()]]).is_integer());
+    assert!(!Matrix::from([[1.into(), Fraction::from((1, 2))]]).is_integer());
+}
+
+#[rstest]
+fn hermite_normal_form(setup: Fixture) {
+    let a = Matrix::from([[2, 3, 6, 2], [5, 6, 1, 6], [8, 3, 1, 1]]);
+    let (h, u) = a.hermite_normal_form();
+    assert_eq!(h, Matrix::from([[1, 0, 50, -11], [0, 3, 28, -2], [0, 0, 61, -13]]));
+    assert_eq!(u, Matrix::from([[9, -5, 1], [5, -2, 0], [11, -6, 1]]));
+    assert_eq!(&u * &a, h);
+
+    // rank deficient, zero rows at the bottom
+    assert_eq!(
+        setup.mat_3x3.hermite_normal_form(),
+        (Matrix::from([[1, 2, 3], [0, 3, 6], [0, 0, 0]]), Matrix::from([[1, 0, 0], [4, -1, 0], [1, -2, 1]]))
+    );
+
+    // the lattice spanned by the rows (4, 0), (0, 1), (2, 1) is 2Z x Z
+    assert_eq!(
+        Matrix::from([[4, 0], [0, 1], [2, 1]]).hermite_normal_form(),
+        (Matrix::from([[2, 0], [0, 1], [0, 0]]), Matrix::from([[0, -1, 1], [0, 1, 0], [1, 2, -2]]))
+    );
+    assert_eq!(
+        Matrix::from([[0, -3, 6], [0, 0, -4]]).hermite_normal_form(),
+        (Matrix::from([[0, 3, 2], [0, 0, 4]]), Matrix::from([[-1, -2], [0, -1]]))
+    );
+    assert_eq!(Matrix::zeros(2, 3).hermite_normal_form(), (Matrix::zeros(2, 3), Matrix::identity(2)));
+    assert_eq!(setup.mat_0x0.hermite_normal_form(), (Matrix::new(), Matrix::new()));
+
+    assert_eq!(
+        Matrix::from([[1.into(), Fraction::from((1, 2))]]).try_hermite_normal_form(),
+        Err(MatrixError::NotInteger { row: 0, col: 1 })
+    );
+}
+
+#[rstest]
+fn smith_normal_form(setup: Fixture) {
+    let a = Matrix::from([[2, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+    let (u, s, v) = a.smith_normal_form();
+    assert_eq!(s, Matrix::from([[2, 0, 0], [0, 6, 0], [0, 0, 12]]));
+    assert_eq!(u, Matrix::from([[1, 0, 0], [3, 1, 0], [1, 2, 1]]));
+    assert_eq!(v, Matrix::from([[1, 0, -2], [0, -1, 4], [0, 1, -3]]));
+    assert_eq!(&u * &a * &v, s);
+
+    // Z^2 / <(2, 0), (0, 3)> = Z/6
+    assert_eq!(
+        Matrix::from([[2, 0], [0, 3]]).smith_normal_form(),
+        (Matrix::from([[1, 1], [3, 2]]), Matrix::from([[1, 0], [0, 6]]), Matrix::from([[-1, 3], [1, -2]]))
+    );
+    assert_eq!(
+        setup.mat_3x3.smith_normal_form(),
+        (
+            Matrix::from([[1, 0, 0], [4, -1, 0], [1, -2, 1]]),
+            Matrix::from([[1, 0, 0], [0, 3, 0], [0, 0, 0]]),
+            Matrix::from([[1, -2, 1], [0, 1, -2], [0, 0, 1]])
+        )
+    );
+    assert_eq!(
+        Matrix::from([[6, 4, 10]]).smith_normal_form(),
+        (Matrix::identity(1), Matrix::from([[2, 0, 0]]), Matrix::from([[1, -2, -1], [-1, 3, -1], [0, 0, 1]]))
+    );
+    assert_eq!(
+        Matrix::from([[0, 0], [-4, 0], [0, 0]]).smith_normal_form(),
+        (
+            Matrix::from([[0, -1, 0], [1, 0, 0], [0, 0, 1]]),
+            Matrix::from([[4, 0], [0, 0], [0, 0]]),
+            Matrix::identity(2)
+        )
+    );
+    assert_eq!(setup.mat_0x0.smith_normal_form(), (Matrix::new(), Matrix::new(), Matrix::new()));
+
+    assert_eq!(
+        Matrix::from([[Fraction::from((1, 3))]]).try_smith_normal_form(),
+        Err(MatrixError::NotInteger { row: 0, col: 0 })
+    );
+}
+
+#[rstest]
+fn solve_integer(setup: Fixture) {
+    // 2x + 4y = 6: x = 3 + 2t, y = -t
+    let a = Matrix::from([[2, 4]]);
+    let Solution::Infinite { particular, basis } = a.solve_integer(&Vector::from([6])) else {
+        panic!();
+    };
+    assert_eq!(&a[0] * &particular, 6.into());
+    assert_eq!(basis.len(), 1);
+    assert_eq!(&a[0] * &basis[0], 0.into());
+    assert!(basis[0] == Vector::from([2, -1]) || basis[0] == Vector::from([-2, 1]));
+
+    // solvable over the rationals but not over the integers
+    assert_eq!(a.solve_integer(&Vector::from([5])), Solution::NoSolution);
+    assert_eq!(a.solve_integer(&Vector::from(vec![Fraction::from((1, 2))])), Solution::NoSolution);
+    assert_eq!(
+        Matrix::from([[2, 0], [0, 3]]).solve_integer(&Vector::from([4, 9])),
+        Solution::Unique(Vector::from([2, 3]))
+    );
+    assert_eq!(Matrix::from([[2, 0], [0, 3]]).solve_integer(&Vector::from([3, 9])), Solution::NoSolution);
+
+    // rank deficient: the third row is twice the second minus the first
+    let b = Vector::from([1, 1, 1]);
+    let Solution::Infinite { particular, basis } = setup.mat_3x3.solve_integer(&b) else {
+        panic!();
+    };
+    assert_eq!(Vector::from((0..3).map(|i| &setup.mat_3x3[i] * &particular).collect::<Vec<_>>()), b);
+    assert_eq!(basis.len(), 1);
+    assert!(basis[0] == Vector::from([1, -2, 1]) || basis[0] == Vector::from([-1, 2, -1]));
+    assert_eq!(setup.mat_3x3.solve_integer(&Vector::from([1, 2, 4])), Solution::NoSolution);
+
+    assert_eq!(a.try_solve_integer(&Vector::from([1, 2])), Err(MatrixError::SizeMismatch { lhs: 1, rhs: 2 }));
+}
+
+#[rstest]
+fn gram_schmidt(setup: Fixture) {
+    let b = Matrix::from([[3, 1], [2, 2]]);
+    let (orthogonal, mu) = b.gram_schmidt();
+    assert_eq!(
+        orthogonal,
+        Matrix::from(vec![
+            vec![Fraction::from(3), Fraction::from(1)],
+            vec![Fraction::from((-2, 5)), Fraction::from((6, 5))]
+        ])
+    );
+    assert_eq!(
+        mu,
+        Matrix::from(vec![vec![Fraction::from(1), Fraction::from(0)], vec![Fraction::from((4, 5)), Fraction::from(1)]])
+    );
+
+    // the third row depends on the first two, so its orthogonal row is zero
+    let (orthogonal, mu) = setup.mat_3x3.gram_schmidt();
+    assert_eq!(&mu * &orthogonal, setup.mat_3x3);
+    assert!(orthogonal[0].is_orthogonal(&orthogonal[1]));
+    assert!(orthogonal[2].is_zero());
+    assert!(mu.is_lower());
+
+    assert_eq!(setup.mat_0x0.gram_schmidt(), (Matrix::new(), Matrix::new()));
+}
+
+#[rstest]
+fn qr_exact(setup: Fixture) {
+    let a = Matrix::from([[3, 2], [1, 2]]);
+    let (q, d, r) = a.qr_exact();
+    assert_eq!(
+        q,
+        Matrix::from(vec![
+            vec![Fraction::from(3), Fraction::from((-2, 5))],
+            vec![Fraction::from(1), Fraction::from((6, 5))]
+        ])
+    );
+    assert_eq!(
+        r,
+        Matrix::from(vec![vec![Fraction::from(1), Fraction::from((4, 5))], vec![Fraction::from(0), Fraction::from(1)]])
+    );
+    assert_eq!(
+        d,
+        Matrix::from(vec![vec![Fraction::from(10), Fraction::from(0)], vec![Fraction::from(0), Fraction::from((8, 5))]])
+    );
+    assert_eq!(q.transpose() * &q, d);
+    assert_eq!(&q * &r, a);
+
+    // a tall and a wide matrix, Q has orthogonal columns, D = Q^T Q and R is unit upper triangular
+    assert_eq!(
+        Matrix::from([[1, 2], [3, 4], [5, 6]]).qr_exact(),
+        (
+            Matrix::from([[35, 26], [105, 8], [175, -10]]) * Fraction::from((1, 35)),
+            Matrix::from([[1225, 0], [0, 24]]) * Fraction::from((1, 35)),
+            Matrix::from([[35, 44], [0, 35]]) * Fraction::from((1, 35))
+        )
+    );
+    assert_eq!(
+        Matrix::from([[1, 0, 2, 1], [0, 1, 1, 3]]).qr_exact(),
+        (
+            Matrix::from([[1, 0, 0, 0], [0, 1, 0, 0]]),
+            Matrix::from([[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]]),
+            Matrix::from([[1, 0, 2, 1], [0, 1, 1, 3], [0, 0, 1, 0], [0, 0, 0, 1]])
+        )
+    );
+    assert_eq!(setup.mat_0x0.qr_exact(), (Matrix::new(), Matrix::new(), Matrix::new()));
+
+    // dependent columns give zero columns in Q and zeros on the diagonal of D
+    assert_eq!(
+        setup.mat_3x3.qr_exact(),
+        (
+            Matrix::from([[11, 9, 0], [44, 3, 0], [77, -3, 0]]) * Fraction::from((1, 11)),
+            Matrix::from([[726, 0, 0], [0, 9, 0], [0, 0, 0]]) * Fraction::from((1, 11)),
+            Matrix::from([[11, 13, 15], [0, 11, 22], [0, 0, 11]]) * Fraction::from((1, 11))
+        )
+    );
+}
+
+#[rstest]
+fn least_squares(setup: Fixture) {
+    // the line y = 5 - 3x through (0, 6), (1, 0), (2, 0)
+    let a = Matrix::from([[1, 0], [1, 1], [1, 2]]);
+    let b = Vector::from([6, 0, 0]);
+    assert_eq!(a.least_squares(&b), (Vector::from([5, -3]), 6.into()));
+    assert_eq!(a.residual(&Vector::from([5, -3]), &b), 6.into());
+    assert_eq!(a.residual(&Vector::from([0, 0]), &b), 36.into());
+
+    // consistent systems have residual 0
+    let a = Matrix::from([[2, 1], [1, 3]]);
+    assert_eq!(a.least_squares(&Vector::from([3, 4])), (Vector::from([1, 1]), 0.into()));
+
+    // rank deficient: x1 + x2 = 2 minimizes, the minimum-norm solution is (1, 1)
+    let a = Matrix::from([[1, 1], [1, 1]]);
+    assert_eq!(a.least_squares(&Vector::from([1, 3])), (Vector::from([1, 1]), 2.into()));
+    let b = Vector::from([1, 0, 2]);
+    let (x, residual) = setup.mat_3x3.least_squares(&b);
+    assert_eq!(x, Vector::from((0..3).map(|i| &setup.mat_3x3.pinv()[i] * &b).collect::<Vec<_>>()));
+    assert_eq!(residual, setup.mat_3x3.residual(&x, &b));
+    assert!(x.is_orthogonal(&Vector::from([1, -2, 1])));
+
+    assert_eq!(a.try_least_squares(&Vector::from([1, 2, 3])), Err(MatrixError::SizeMismatch { lhs: 2, rhs: 3 }));
+    assert_eq!(
+        a.try_residual(&Vector::from([1]), &Vector::from([1, 2])),
+        Err(MatrixError::SizeMismatch { lhs: 2, rhs: 1 })
+    );
+}
+
+#[rstest]
+fn pinv(setup: Fixture) {
+    let a = Matrix::from([[1, 2], [3, 4]]);
+    assert_eq!(a.pinv(), a.inv().unwrap());
+    assert_eq!(Matrix::from([[1, 1], [1, 1]]).pinv(), Matrix::from([[1, 1], [1, 1]]) * Fraction::from((1, 4)));
+    assert_eq!(Matrix::from([[1, 2, 2]]).pinv(), Matrix::from([[1], [2], [2]]) * Fraction::from((1, 9)));
+
+    // full column rank: (A^T A)^-1 A^T
+    assert_eq!(
+        Matrix::from([[1, 0], [1, 1], [1, 2]]).pinv(),
+        Matrix::from([[5, 2, -1], [-3, 0, 3]]) * Fraction::from((1, 6))
+    );
+
+    // rank deficient, and the Penrose conditions: A X A = A, X A X = X, A X and X A symmetric
+    let x = setup.mat_3x3.pinv();
+    assert_eq!(x, Matrix::from([[-23, -6, 11], [-2, 0, 2], [19, 6, -7]]) * Fraction::from((1, 36)));
+    assert_eq!(&setup.mat_3x3 * &x * &setup.mat_3x3, setup.mat_3x3);
+    assert_eq!(&x * &setup.mat_3x3 * &x, x);
+    assert!((&setup.mat_3x3 * &x).is_symmetric() && (&x * &setup.mat_3x3).is_symmetric());
+
+    assert_eq!(Matrix::zeros(2, 3).pinv(), Matrix::zeros(3, 2));
+    assert_eq!(setup.mat_0x0.pinv(), setup.mat_0x0);
+}
+
+#[rstest]
+fn projection_onto_column_space(setup: Fixture) {
+    let a = Matrix::from([[1, 0], [1, 1], [1, 2]]);
+    let p = a.projection_onto_column_space();
+    assert_eq!(p, Matrix::from([[5, 2, -1], [2, 2, 2], [-1, 2, 5]]) * Fraction::from((1, 6)));
+    assert_eq!(&p * &p, p);
+    assert!(p.is_symmetric());
+    assert_eq!(&p * &a, a);
+
+    // the column space of mat_3x3 is the plane orthogonal to (1, -2, 1)
+    let p = setup.mat_3x3.projection_onto_column_space();
+    assert_eq!(p, Matrix::identity(3) - Matrix::from([[1, -2, 1], [-2, 4, -2], [1, -2, 1]]) * Fraction::from((1, 6)));
+    assert_eq!(Matrix::from([[1, 2], [3, 4]]).projection_onto_column_space(), Matrix::identity(2));
+    assert_eq!(Matrix::zeros(2, 3).projection_onto_column_space(), Matrix::zeros(2, 2));
+}
+
+#[rstest]
+fn projector_onto_span() {
+    let (u, v) = (Vector::from([1, 1, 1]), Vector::from([1, 2, 3]));
+    let p = projector(&[u.clone(), v.clone()]);
+    assert_eq!(p, Matrix::from([[1, 0], [1, 1], [1, 2]]).projection_onto_column_space());
+
+    // dependent vectors span the same line
+    let p = projector(&[u.clone(), u.clone() * Fraction::from(-2)]);
+    assert_eq!(p, Matrix::ones(3, 3) * Fraction::from((1, 3)));
+    assert_eq!(projector(&[Vector::zeros(2)]), Matrix::zeros(2, 2));
+
+    assert_eq!(try_projector::<Fraction>(&[]), Err(MatrixError::Empty));
+    assert_eq!(try_projector(&[u, Vector::from([1, 2])]), Err(MatrixError::SizeMismatch { lhs: 3, rhs: 2 }));
+}
+
+#[rstest]
+fn lll(setup: Fixture) {
+    let delta = Fraction::from((3, 4));
+    let b = Matrix::from([[1, 1, 1], [-1, 0, 2], [3, 5, 6]]);
+    let (reduced, u) = b.lll(delta);
+    assert_eq!(reduced, Matrix::from([[0, 1, 0], [1, 0, 1], [-1, 0, 2]]));
+    assert_eq!(&u * &b, reduced);
+    assert!(u.is_integer() && u.det().abs() == 1.into());
+
+    // size-reduced and the Lovasz condition
+    let b = Matrix::from([[201, 37, 0, 0], [1648, 297, 0, 0], [0, 0, 1, 1], [12, 7, 3, -5]]);
+    let (reduced, u) = b.lll(Fraction::from((99, 100)));
+    assert_eq!(&u * &b, reduced);
+    assert_eq!(u.det().abs(), 1.into());
+    let (orthogonal, mu) = reduced.gram_schmidt();
+    for k in 1..4 {
+        assert!((0..k).all(|j| mu[k][j].abs() <= Fraction::from((1, 2))));
+        let (a, b) = (&orthogonal[k] * &orthogonal[k], &orthogonal[k - 1] * &orthogonal[k - 1]);
+        assert!(a >= (Fraction::from((99, 100)) - mu[k][k - 1] * mu[k][k - 1]) * b);
+    }
+
+    assert_eq!(Matrix::from([[5, 0]]).lll(delta), (Matrix::from([[5, 0]]), Matrix::identity(1)));
+    assert_eq!(setup.mat_0x0.lll(delta), (Matrix::new(), Matrix::new()));
+    assert_eq!(setup.mat_3x3.try_lll(delta), Err(MatrixError::LinearlyDependent));
+}
+
+#[rstest]
+fn bad_lll() {
+    let delta = Fraction::from((1, 4));
+    assert_eq!(Matrix::identity(2).try_lll(delta), Err(MatrixError::InvalidDelta { delta }));
+    let delta = Fraction::from((3, 2));
+    assert_eq!(Matrix::identity(2).try_lll(delta), Err(MatrixError::InvalidDelta { delta }));
+}
+
+#[rstest]
+fn inv_mod(setup: Fixture) {
+    // Hill cipher key modulo 26: det = 9, 9^-1 = 3 (mod 26)
+    let key = Matrix::from([[3, 3], [2, 5]]);
+    let inv = key.inv_mod(26).unwrap();
+    assert_eq!(inv, Matrix::from([[15, 17], [20, 9]]));
+    assert_eq!((&key * &inv).try_inv_mod(26), Ok(Matrix::identity(2)));
+
+    // gcd(det, m) != 1
+    assert_eq!(Matrix::from([[2, 0], [0, 1]]).inv_mod(26), None);
+    assert_eq!(Matrix::from([[2, 0], [0, 1]]).inv_mod(27), Some(Matrix::from([[14, 0], [0, 1]])));
+    assert_eq!(Matrix::from([[2, 1], [1, 2]]).try_inv_mod(6), Err(MatrixError::NotInvertible { modulus: 6 }));
+    assert_eq!(Matrix::from([[2, 1], [1, 2]]).inv_mod(8), Some(Matrix::from([[6, 5], [5, 6]])));
+    assert_eq!(setup.mat_3x3.inv_mod(5), None);
+    assert_eq!(setup.mat_3x3.try_inv_mod(5), Err(MatrixError::NotInvertible { modulus: 5 }));
+
+    // fractions with denominators coprime with m are reduced first: 1/3 = 9 (mod 26)
+    assert_eq!(Matrix::from([[Fraction::from((1, 3))]]).inv_mod(26), Some(Matrix::from([[3]])));
+    assert_eq!(Matrix::from([[Fraction::from((1, 2))]]).inv_mod(26), None);
+
+    assert_eq!(setup.mat_0x0.inv_mod(26), Some(Matrix::new()));
+    assert_eq!(setup.mat_1x1.inv_mod(1), Some(Matrix::from([[0]])));
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_inv_mod(26), Err(MatrixError::NotSquare { shape: (1, 3) }));
+    assert_eq!(setup.mat_1x1.try_inv_mod(0), Err(MatrixError::ZeroModulus));
+
+    // residues close to 2^64 do not overflow, for a prime and a composite modulus: [[-1, -2], [-3, -5]]^-1 = [[5, -2], [-3, 1]]
+    for m in [u64::MAX - 58, u64::MAX] {
+        let a = Matrix::from(vec![
+            vec![Fraction::from((m - 1) as i128), Fraction::from((m - 2) as i128)],
+            vec![Fraction::from((m - 3) as i128), Fraction::from((m - 5) as i128)],
+        ]);
+        let inv = Matrix::from(vec![
+            vec![Fraction::from(5), Fraction::from((m - 2) as i128)],
+            vec![Fraction::from((m - 3) as i128), Fraction::from(1)],
+        ]);
+        assert_eq!(a.inv_mod(m), Some(inv));
+    }
+
+    // the least absolute residues are too large for the exact det and adj
+    let m = u64::MAX;
+    let a = Matrix::from(vec![
+        vec![Fraction::from((m / 3) as i128), Fraction::from(1)],
+        vec![Fraction::from(1), Fraction::from((m / 3) as i128)],
+    ]);
+    assert_eq!(a.try_inv_mod(m), Err(MatrixError::Overflow));
+
+    // 4 and 9 are not invertible modulo 12 but [[4, 1], [9, 1]] is, det = -5
+    assert_eq!(Matrix::from([[4, 1], [9, 1]]).inv_mod(12), Some(Matrix::from([[7, 5], [9, 4]])));
+    assert_eq!(Matrix::from([[4, 2], [6, 3]]).inv_mod(12), None);
+}
+
+#[rstest]
+fn solve_mod() {
+    // encrypt "HI" = [7, 8] with the Hill cipher key, then decrypt
+    let key = Matrix::from([[3, 3], [2, 5]]);
+    let cipher = Vector::from([(3 * 7 + 3 * 8) % 26, (2 * 7 + 5 * 8) % 26]);
+    assert_eq!(key.solve_mod(&cipher, 26), Some(Vector::from([7, 8])));
+    assert_eq!(key.solve_mod(&Vector::from([-1, 27]), 26), key.solve_mod(&Vector::from([25, 1]), 26));
+
+    assert_eq!(Matrix::from([[2, 0], [0, 1]]).solve_mod(&Vector::from([1, 1]), 26), None);
+    assert_eq!(key.try_solve_mod(&Vector::from([1, 2, 3]), 26), Err(MatrixError::SizeMismatch { lhs: 2, rhs: 3 }));
+    assert_eq!(key.try_solve_mod(&cipher, 0), Err(MatrixError::ZeroModulus));
+}
+
+#[rstest]
+fn solve(setup: Fixture) {
+    // unique solution
+    let a = Matrix::from([[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]);
+    assert_eq!(a.solve(&Vector::from([8, -11, -3])), Solution::Unique(Vector::from([2, 3, -1])));
+    assert_eq!(setup.mat_1x1.solve(&Vector::from([1])), Solution::Unique(Vector::from([Fraction::from((1, 2))])));
+
+    // no solution
+    assert_eq!(setup.mat_3x3.solve(&Vector::from([1, 1, 0])), Solution::NoSolution);
+    assert!(!Matrix::from([[0, 0]]).solve(&Vector::from([1])).is_consistent());
+
+    // infinitely many solutions
+    assert_eq!(
+        setup.mat_3x3.solve(&Vector::from([6, 15, 24])),
+        Solution::Infinite {
+            particular: Vector::from([0, 3, 0]),
+            basis: vec![Vector::from([1, -2, 1])]
+        }
+    );
+    assert_eq!(
+        Matrix::from([[0, 2, 4], [0, 0, 3]]).solve(&Vector::from([2, 3])),
+        Solution::Infinite {
+            particular: Vector::from([0, -1, 1]),
+            basis: vec![Vector::from([1, 0, 0])]
+        }
+    );
+    assert_eq!(
+        Matrix::from([[1, 1, 1, 1]]).solve(&Vector::from([4])),
+        Solution::Infinite {
+            particular: Vector::from([4, 0, 0, 0]),
+            basis: vec![Vector::from([-1, 1, 0, 0]), Vector::from([-1, 0, 1, 0]), Vector::from([-1, 0, 0, 1])]
+        }
+    );
+
+    // right-hand side matrix
+    let b = Matrix::from([[8, 1], [-11, 0], [-3, 0]]);
+    match a.solve_matrix(&b) {
+        Solution::Unique(x) => assert_eq!(&a * &x, b),
+        _ => panic!("expect a unique solution"),
+    }
+    assert_eq!(a.solve_matrix(&Matrix::identity(3)), Solution::Unique(a.inv().unwrap()));
+    assert_eq!(setup.mat_3x3.solve_matrix(&Matrix::identity(3)), Solution::NoSolution);
+
+    // dimensions mismatch
+    assert_eq!(setup.mat_3x3.try_solve(&Vector::from([1, 2])), Err(MatrixError::SizeMismatch { lhs: 3, rhs: 2 }));
+    assert_eq!(
+        setup.mat_3x3.try_solve_matrix(&Matrix::zeros(2, 2)),
+        Err(MatrixError::ShapeMismatch { lhs: (3, 3), rhs: (2, 2) })
+    );
+}
+
+#[rstest]
+fn null_space(setup: Fixture) {
+    assert_eq!(setup.mat_0x0.null_space(), vec![]);
+    assert_eq!(setup.mat_1x1.null_space(), vec![]);
+    assert_eq!(setup.mat_3x3.null_space(), vec![Vector::from([1, -2, 1])]);
+    assert_eq!(Matrix::zeros(2, 2).null_space(), vec![Vector::from([1, 0]), Vector::from([0, 1])]);
+    assert_eq!(Matrix::from([[0, 2, 4], [0, 0, 3]]).null_space(), vec![Vector::from([1, 0, 0])]);
+
+    // every basis vector is a solution of A x = 0
+    let a = Matrix::from([[1, 2, 3, 4], [2, 4, 7, 9]]);
+    let basis = a.null_space();
+    assert_eq!(basis.len(), 4 - a.rank());
+    for v in &basis {
+        for r in 0..a.row_size() {
+            assert_eq!(&a[r] * v, 0.into());
+        }
+    }
+
+    // balance the chemical equation C3H8 + O2 -> CO2 + H2O, rows are elements C, H, O
+    let reaction = Matrix::from([[3, 0, -1, 0], [8, 0, 0, -2], [0, 2, -2, -1]]);
+    assert_eq!(
+        reaction.null_space(),
+        vec![Vector::from([Fraction::from((1, 4)), Fraction::from((5, 4)), Fraction::from((3, 4)), 1.into()])]
+    );
+    assert_eq!(reaction.null_space_primitive(), vec![Vector::from([1, 5, 3, 4])]);
+}
+
+#[rstest]
+fn column_space(setup: Fixture) {
+    assert_eq!(setup.mat_0x0.column_space(), vec![]);
+    assert_eq!(setup.mat_1x1.column_space(), vec![Vector::from([2])]);
+    assert_eq!(setup.mat_3x3.column_space(), vec![Vector::from([1, 4, 7]), Vector::from([2, 5, 8])]);
+    assert_eq!(Matrix::zeros(2, 2).column_space(), vec![]);
+    assert_eq!(Matrix::from([[0, 2, 4], [0, 0, 3]]).column_space(), vec![Vector::from([2, 0]), Vector::from([4, 3])]);
+}
+
+#[rstest]
+fn row_space(setup: Fixture) {
+    assert_eq!(setup.mat_0x0.row_space(), vec![]);
+    assert_eq!(setup.mat_1x1.row_space(), vec![Vector::from([1])]);
+    assert_eq!(setup.mat_3x3.row_space(), vec![Vector::from([1, 0, -1]), Vector::from([0, 1, 2])]);
+    assert_eq!(Matrix::zeros(2, 2).row_space(), vec![]);
+    assert_eq!(
+        Matrix::from([[0, 2, 4], [0, 0, 3]]).row_space(),
+        vec![Vector::from([0, 1, 0]), Vector::from([0, 0, 1])]
+    );
+}
+
+#[rstest]
+fn left_null_space(setup: Fixture) {
+    assert_eq!(setup.mat_0x0.left_null_space(), vec![]);
+    assert_eq!(setup.mat_1x1.left_null_space(), vec![]);
+    assert_eq!(setup.mat_3x3.left_null_space(), vec![Vector::from([1, -2, 1])]);
+    assert_eq!(
+        Matrix::from([[1, 2], [2, 4], [3, 6]]).left_null_space(),
+        vec![Vector::from([-2, 1, 0]), Vector::from([-3, 0, 1])]
+    );
+
+    // rank-nullity: dim(row space) + dim(left null space) = number of rows
+    let a = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    assert_eq!(a.column_space().len() + a.left_null_space().len(), a.row_size());
+}
+
+#[rstest]
+fn plu_decomposition(setup: Fixture) {
+    // needs row pivoting
+    let a = Matrix::from([[0, 1], [1, 0]]);
+    assert_eq!(a.plu_decomposition(), (Matrix::from([[0, 1], [1, 0]]), Matrix::identity(2), Matrix::identity(2)));
+
+    // P A = L U for square, singular and rectangular matrices
+    for a in [
+        setup.mat_0x0,
+        setup.mat_1x1,
+        setup.mat_3x3,
+        Matrix::from([[0, 2, 4], [0, 0, 3]]),
+        Matrix::from([[1, 2], [3, 4], [5, 6]]),
+        Matrix::from([[0, 0, 1], [0, 2, 0], [3, 0, 0]]),
+        Matrix::from([[2, 3, 1], [4, 7, 1], [6, 7, 3]]),
+        Matrix::zeros(2, 3),
+    ] {
+        let (p, l, u) = a.plu_decomposition();
+        assert_eq!(&p * &a, &l * &u);
+        assert!(l.is_lower());
+        assert!((0..l.row_size()).all(|i| l[i][i] == 1.into()));
+        assert!((1..u.row_size()).all(|r| (0..r.min(u.col_size())).all(|c| u[r][c] == 0.into())));
+    }
+
+    assert_eq!(
+        Matrix::from([[1, 2], [3, 4]]).plu(),
+        Plu {
+            perm: vec![0, 1],
+            l: Matrix::from([[1, 0], [3, 1]]),
+            u: Matrix::from([[1, 2], [0, -2]])
+        }
+    );
+}
+
+#[rstest]
+fn plu(setup: Fixture) {
+    // determinant
+    assert_eq!(setup.mat_0x0.plu().det(), 1.into());
+    assert_eq!(setup.mat_1x1.plu().det(), 2.into());
+    assert_eq!(setup.mat_3x3.plu().det(), 0.into());
+    assert_eq!(Matrix::from([[0, 1], [1, 0]]).plu().det(), (-1).into());
+    assert_eq!(Matrix::from([[0, 0, 1], [0, 2, 0], [3, 0, 0]]).plu().det(), (-6).into());
+    assert_eq!(Matrix::from([[0, 1, 0], [0, 0, 1], [1, 0, 0]]).plu().det(), 1.into());
+    assert_eq!(Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 0]]).plu().det(), 27.into());
+    assert_eq!(Matrix::from([[1, 2, 3]]).plu().try_det(), Err(MatrixError::NotSquare { shape: (1, 3) }));
+
+    // solve many right-hand sides with one factorization
+    let a = Matrix::from([[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]);
+    let plu = a.plu();
+    assert_eq!(plu.solve(&Vector::from([8, -11, -3])), Vector::from([2, 3, -1]));
+    assert_eq!(plu.solve(&Vector::from([2, -3, -2])), Vector::from([1, 0, 0]));
+    assert_eq!(plu.solve_matrix(&Matrix::identity(3)), a.inv().unwrap());
+    assert_eq!(plu.try_solve(&Vector::from([1, 2])), Err(MatrixError::SizeMismatch { lhs: 3, rhs: 2 }));
+
+    assert_eq!(setup.mat_3x3.plu().try_solve(&Vector::from([1, 2, 3])), Err(MatrixError::Singular));
+    assert_eq!(
+        Matrix::from([[1, 2], [3, 4], [5, 6]]).plu().try_solve(&Vector::from([1, 2, 3])),
+        Err(MatrixError::NotSquare { shape: (3, 2) })
+    );
+}
+
+#[rstest]
+fn ldl(setup: Fixture) {
+    // no pivoting needed
+    let a = Matrix::from([[4, 2, -2], [2, 10, 2], [-2, 2, 5]]);
+    let ldl = a.ldl();
+    assert_eq!(ldl.perm, vec![0, 1, 2]);
+    assert_eq!(ldl.d, Matrix::from([[4, 0, 0], [0, 9, 0], [0, 0, 3]]));
+    assert_eq!(
+        ldl.l,
+        Matrix::from(vec![
+            vec![Fraction::from(1), 0.into(), 0.into()],
+            vec![Fraction::from((1, 2)), 1.into(), 0.into()],
+            vec![Fraction::from((-1, 2)), Fraction::from((1, 3)), 1.into()]
+        ])
+    );
+    assert_eq!(ldl.blocks, vec![1, 1, 1]);
+
+    // a zero on the diagonal is skipped by a symmetric swap
+    assert_eq!(
+        Matrix::from([[0, 1, 2], [1, 3, 1], [2, 1, 1]]).ldl(),
+        Ldl {
+            perm: vec![1, 0, 2],
+            l: Matrix::from(vec![
+                vec![Fraction::from(1), 0.into(), 0.into()],
+                vec![Fraction::from((1, 3)), 1.into(), 0.into()],
+                vec![Fraction::from((1, 3)), (-5).into(), 1.into()]
+            ]),
+            d: Matrix::from(vec![
+                vec![Fraction::from(3), 0.into(), 0.into()],
+                vec![0.into(), Fraction::from((-1, 3)), 0.into()],
+                vec![0.into(), 0.into(), 9.into()]
+            ]),
+            blocks: vec![1, 1, 1],
+        }
+    );
+
+    // an all zero diagonal needs a 2 x 2 block
+    let a = Matrix::from([[0, 1], [1, 0]]);
+    assert_eq!(
+        a.ldl(),
+        Ldl {
+            perm: vec![0, 1],
+            l: Matrix::identity(2),
+            d: a.clone(),
+            blocks: vec![2],
+        }
+    );
+    let a = Matrix::from([[0, 0, 2, 1], [0, 0, 1, 3], [2, 1, 0, 0], [1, 3, 0, 0]]);
+    assert_eq!(
+        a.ldl(),
+        Ldl {
+            perm: vec![0, 2, 1, 3],
+            l: Matrix::from([[2, 0, 0, 0], [0, 2, 0, 0], [1, 0, 2, 0], [0, 1, 0, 2]]) * Fraction::from((1, 2)),
+            d: Matrix::from([[0, 4, 0, 0], [4, 0, 0, 0], [0, 0, 0, 5], [0, 0, 5, 0]]) * Fraction::from((1, 2)),
+            blocks: vec![2, 2],
+        }
+    );
+    let (p, l, d) = a.ldl_decomposition();
+    assert_eq!(&p * &a * p.transpose(), &l * &d * l.transpose());
+
+    assert_eq!(
+        Matrix::from([[0, 0, 0], [0, 0, 3], [0, 3, 0]]).ldl(),
+        Ldl {
+            perm: vec![1, 2, 0],
+            l: Matrix::identity(3),
+            d: Matrix::from([[0, 3, 0], [3, 0, 0], [0, 0, 0]]),
+            blocks: vec![2, 1],
+        }
+    );
+    assert_eq!(
+        Matrix::from([[1, 1, 1], [1, 1, 1], [1, 1, 1]]).ldl(),
+        Ldl {
+            perm: vec![0, 1, 2],
+            l: Matrix::from([[1, 0, 0], [1, 1, 0], [1, 0, 1]]),
+            d: Matrix::from([[1, 0, 0], [0, 0, 0], [0, 0, 0]]),
+            blocks: vec![1, 1, 1],
+        }
+    );
+    assert_eq!(
+        setup.mat_0x0.ldl(),
+        Ldl {
+            perm: vec![],
+            l: Matrix::new(),
+            d: Matrix::new(),
+            blocks: vec![],
+        }
+    );
+
+    assert_eq!(setup.mat_3x3.try_ldl(), Err(MatrixError::NotSymmetric));
+    assert_eq!(Matrix::from([[1, 2, 3]]).try_ldl(), Err(MatrixError::NotSquare { shape: (1, 3) }));
+}
+
+#[rstest]
+fn inertia(setup: Fixture) {
+    assert_eq!(Matrix::from([[4, 2, -2], [2, 10, 2], [-2, 2, 5]]).inertia(), (3, 0, 0));
+    assert_eq!(Matrix::from([[0, 1], [1, 0]]).inertia(), (1, 1, 0));
+    assert_eq!(Matrix::from([[1, 1, 1], [1, 1, 1], [1, 1, 1]]).inertia(), (1, 0, 2));
+    assert_eq!(Matrix::from([[0, 0, 2, 1], [0, 0, 1, 3], [2, 1, 0, 0], [1, 3, 0, 0]]).inertia(), (2, 2, 0));
+    assert_eq!(Matrix::from([[-2, 1, 0], [1, -2, 1], [0, 1, -2]]).inertia(), (0, 3, 0));
+    assert_eq!(Matrix::zeros(2, 2).inertia(), (0, 0, 2));
+    assert_eq!(setup.mat_0x0.inertia(), (0, 0, 0));
+    assert_eq!(setup.mat_3x3.try_inertia(), Err(MatrixError::NotSymmetric));
+}
+
+#[rstest]
+fn definiteness(setup: Fixture) {
+    let pd = Matrix::from([[2, -1, 0], [-1, 2, -1], [0, -1, 2]]);
+    assert!(pd.is_positive_definite() && pd.is_positive_semidefinite() && !pd.is_negative_definite());
+
+    let nd = pd.clone() * -1;
+    assert!(nd.is_negative_definite() && !nd.is_positive_semidefinite());
+
+    let psd = Matrix::from([[1, 1, 1], [1, 1, 1], [1, 1, 1]]);
+    assert!(psd.is_positive_semidefinite() && !psd.is_positive_definite());
+
+    let indefinite = Matrix::from([[1, 2], [2, 1]]);
+    assert!(!indefinite.is_positive_semidefinite() && !indefinite.is_negative_definite());
+
+    // not symmetric, although x^T A x = x1^2 + x2^2 > 0
+    assert!(!Matrix::from([[1, 1], [-1, 1]]).is_positive_definite());
+    assert!(!setup.mat_3x3.is_positive_semidefinite());
+    assert!(setup.mat_0x0.is_positive_definite());
 }
 
 #[rstest]
@@ -279,6 +1458,55 @@ fn mul() {
     assert_eq!(Matrix::from([[1, 2], [3, 4]]) * Fraction::from(3), Matrix::from([[3, 6], [9, 12]]));
 }
 
+#[rstest]
+fn try_operations(setup: Fixture) {
+    let rect = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+
+    assert_eq!(setup.mat_3x3.try_trace(), Ok(15.into()));
+    assert_eq!(rect.try_trace(), Err(MatrixError::NotSquare { shape: (2, 3) }));
+
+    assert_eq!(setup.mat_3x3.try_det(), Ok(0.into()));
+    assert_eq!(rect.try_det(), Err(MatrixError::NotSquare { shape: (2, 3) }));
+
+    assert_eq!(setup.mat_1x1.try_inv(), Ok(Matrix::from([[Fraction::from((1, 2))]])));
+    assert_eq!(setup.mat_3x3.try_inv(), Err(MatrixError::Singular));
+    assert_eq!(rect.try_inv(), Err(MatrixError::NotSquare { shape: (2, 3) }));
+
+    assert_eq!(Matrix::from([[0, 1], [1, 0]]).try_lu_decomposition(), Err(MatrixError::ZeroPivot { index: 0 }));
+    assert_eq!(rect.try_lu_decomposition(), Err(MatrixError::NotSquare { shape: (2, 3) }));
+
+    assert_eq!(rect.try_split_row(1), Ok((Matrix::from([[1, 2, 3]]), Matrix::from([[4, 5, 6]]))));
+    assert_eq!(rect.try_split_row(2), Err(MatrixError::IndexOutOfRange { index: 2, begin: 0, end: 2 }));
+    assert_eq!(rect.try_split_col(3), Err(MatrixError::IndexOutOfRange { index: 3, begin: 0, end: 3 }));
+
+    assert_eq!(rect.clone().try_expand_row(Matrix::zeros(1, 3)), Ok(&Matrix::from([[1, 2, 3], [4, 5, 6], [0, 0, 0]])));
+    assert_eq!(
+        rect.clone().try_expand_row(Matrix::zeros(1, 2)),
+        Err(MatrixError::ShapeMismatch { lhs: (2, 3), rhs: (1, 2) })
+    );
+    assert_eq!(
+        rect.clone().try_expand_col(Matrix::zeros(3, 1)),
+        Err(MatrixError::ShapeMismatch { lhs: (2, 3), rhs: (3, 1) })
+    );
+
+    assert_eq!(rect.try_add(&rect), Ok(2 * rect.clone()));
+    assert_eq!(rect.try_sub(&setup.mat_3x3), Err(MatrixError::ShapeMismatch { lhs: (2, 3), rhs: (3, 3) }));
+    assert_eq!(rect.try_mul(&setup.mat_3x3), Ok(Matrix::from([[30, 36, 42], [66, 81, 96]])));
+    assert_eq!(rect.try_mul(&rect), Err(MatrixError::ShapeMismatch { lhs: (2, 3), rhs: (2, 3) }));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The matrix is not a square matrix.")]
+fn bad_det() {
+    Matrix::from([[1, 2, 3]]).det();
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The dimensions mismatch.")]
+fn bad_mul() {
+    let _ = Matrix::zeros(2, 3) * Matrix::zeros(2, 3);
+}
+
 #[rstest]
 fn format(setup: Fixture) {
     assert_eq!(format!("{}", setup.mat_0x0), "[\n]");
@@ -0,0 +1,192 @@
+use mymatrix::{Matrix, MatrixError, Polynomial};
+use pyinrs::Fraction;
+use rstest::{fixture, rstest};
+
+struct Fixture {
+    zero: Polynomial,
+    one: Polynomial,
+    cubic: Polynomial,
+}
+
+#[fixture]
+fn setup() -> Fixture {
+    Fixture {
+        zero: Polynomial::new(),
+        one: Polynomial::from([1]),
+        // (x - 1)(x - 2)(x - 3)
+        cubic: Polynomial::from([-6, 11, -6, 1]),
+    }
+}
+
+#[rstest]
+fn basics(setup: Fixture) {
+    assert_eq!(setup.zero.degree(), None);
+    assert!(setup.zero.is_zero());
+    assert_eq!(setup.zero.coeffs(), &[]);
+
+    assert_eq!(setup.one.degree(), Some(0));
+    assert!(setup.one.is_monic());
+
+    assert_eq!(setup.cubic.degree(), Some(3));
+    assert_eq!(setup.cubic.coeff(1), 11.into());
+    assert_eq!(setup.cubic.coeff(5), 0.into());
+    assert_eq!(setup.cubic.leading_coeff(), 1.into());
+
+    assert_eq!(Polynomial::from([1, 2, 0, 0]).degree(), Some(1));
+    assert_eq!(Polynomial::from([0, 0]), setup.zero);
+    assert_eq!(Polynomial::monomial(3.into(), 2), Polynomial::from([0, 0, 3]));
+    assert_eq!(Polynomial::from([2, 4]).monic(), Polynomial::from([Fraction::from((1, 2)), 1.into()]));
+}
+
+#[rstest]
+fn eval(setup: Fixture) {
+    assert_eq!(setup.zero.eval(5.into()), 0.into());
+    assert_eq!(setup.cubic.eval(2.into()), 0.into());
+    assert_eq!(setup.cubic.eval(4.into()), 6.into());
+    assert_eq!(setup.cubic.eval(Fraction::from((1, 2))), Fraction::from((-15, 8)));
+}
+
+#[rstest]
+fn eval_matrix(setup: Fixture) {
+    let a = Matrix::from([[1, 2], [3, 4]]);
+    assert_eq!(setup.zero.eval_matrix(&a), Matrix::zeros(2, 2));
+    assert_eq!(setup.one.eval_matrix(&a), Matrix::identity(2));
+    assert_eq!(Polynomial::from([1, 2, 1]).eval_matrix(&a), &a * &a + &a * Fraction::from(2) + Matrix::identity(2));
+
+    // the diagonal is evaluated elementwise, and Cayley-Hamilton
+    let d = Matrix::from([[1, 0, 0], [0, 2, 0], [0, 0, 4]]);
+    assert_eq!(setup.cubic.eval_matrix(&d), Matrix::from([[0, 0, 0], [0, 0, 0], [0, 0, 6]]));
+    assert_eq!(a.char_poly().eval_matrix(&a), Matrix::zeros(2, 2));
+
+    assert_eq!(setup.cubic.try_eval_matrix(&Matrix::from([[1, 2]])), Err(MatrixError::NotSquare { shape: (1, 2) }));
+}
+
+#[rstest]
+fn arithmetic(setup: Fixture) {
+    let a = Polynomial::from([-1, 1]); // x - 1
+    let b = Polynomial::from([-2, 1]); // x - 2
+    let c = Polynomial::from([-3, 1]); // x - 3
+
+    assert_eq!(&a * &b * &c, setup.cubic);
+    assert_eq!(&a + &b, Polynomial::from([-3, 2]));
+    assert_eq!(&a - &a, setup.zero);
+    assert_eq!(-&a, Polynomial::from([1, -1]));
+    assert_eq!(&a * Fraction::from(2), Polynomial::from([-2, 2]));
+    assert_eq!(&a * &setup.zero, setup.zero);
+
+    assert_eq!(&setup.cubic / &a, &b * &c);
+    assert_eq!(&setup.cubic % &a, setup.zero);
+    assert_eq!(
+        setup.cubic.div_rem(&Polynomial::from([0, 1, 1])),
+        (Polynomial::from([-7, 1]), Polynomial::from([-6, 18]))
+    );
+    assert_eq!(setup.one.div_rem(&setup.cubic), (setup.zero.clone(), setup.one.clone()));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Divide by zero.")]
+fn divide_by_zero(setup: Fixture) {
+    let _ = setup.cubic / setup.zero;
+}
+
+#[rstest]
+fn derivative(setup: Fixture) {
+    assert_eq!(setup.cubic.derivative(), Polynomial::from([11, -12, 3]));
+    assert_eq!(setup.one.derivative(), setup.zero);
+    assert_eq!(setup.zero.derivative(), setup.zero);
+}
+
+#[rstest]
+fn gcd(setup: Fixture) {
+    let a = Polynomial::from([-1, 1]); // x - 1
+    let b = Polynomial::from([-2, 1]); // x - 2
+    let d = Polynomial::from([-4, 2]) * &a; // 2(x - 2)(x - 1)
+
+    assert_eq!(Polynomial::gcd(&setup.cubic, &d), &a * &b);
+    assert_eq!(Polynomial::gcd(&a, &b), setup.one);
+    assert_eq!(Polynomial::gcd(&setup.zero, &d), &a * &b);
+    assert_eq!(Polynomial::gcd(&setup.zero, &setup.zero), setup.zero);
+
+    // a square-free test: gcd(p, p') = 1 iff p has no repeated root
+    assert_eq!(Polynomial::gcd(&setup.cubic, &setup.cubic.derivative()), setup.one);
+    let square = &a * &a * &b;
+    assert_eq!(Polynomial::gcd(&square, &square.derivative()), a);
+}
+
+#[rstest]
+fn square_free(setup: Fixture) {
+    let a = Polynomial::from([-1, 1]); // x - 1
+    let b = Polynomial::from([1, 0, 1]); // x^2 + 1
+
+    assert_eq!(setup.zero.square_free(), setup.zero);
+    assert_eq!(setup.one.square_free(), setup.one);
+    assert_eq!(setup.cubic.square_free(), setup.cubic);
+    assert_eq!((&a * &a * &a * &b * &b * Fraction::from(3)).square_free(), &a * &b);
+    assert_eq!(Polynomial::monomial(2.into(), 4).square_free(), Polynomial::from([0, 1]));
+}
+
+#[rstest]
+fn rational_roots(setup: Fixture) {
+    assert_eq!(setup.zero.rational_roots(), vec![]);
+    assert_eq!(setup.one.rational_roots(), vec![]);
+    assert_eq!(setup.cubic.rational_roots(), vec![(1.into(), 1), (2.into(), 1), (3.into(), 1)]);
+
+    // x^2 (2x + 1)^3 (x - 3)^2 (x^2 - 2)
+    let (a, b) = (Polynomial::from([1, 2]), Polynomial::from([-3, 1]));
+    let p = Polynomial::from([0, 0, 1]) * &a * &a * &a * &b * &b * Polynomial::from([-2, 0, 1]);
+    assert_eq!(p.rational_roots(), vec![(Fraction::from((-1, 2)), 3), (0.into(), 2), (3.into(), 2)]);
+
+    // fraction coefficients: (x - 2/3)(x + 5/7)
+    let p = Polynomial::from([Fraction::from((-2, 3)), 1.into()]) * Polynomial::from([Fraction::from((5, 7)), 1.into()]);
+    assert_eq!(p.rational_roots(), vec![(Fraction::from((-5, 7)), 1), (Fraction::from((2, 3)), 1)]);
+
+    // no rational root
+    assert_eq!(Polynomial::from([1, 0, 1]).rational_roots(), vec![]);
+
+    // the content is divided out: 2^60 (x - 1)(x + 3), and a large prime constant term does not take long
+    let p = Polynomial::from([-3, 2, 1]) * Fraction::from(1i64 << 60);
+    assert_eq!(p.rational_roots(), vec![((-3).into(), 1), (1.into(), 1)]);
+    let prime = (1i64 << 61) - 1;
+    assert_eq!(
+        Polynomial::from([Fraction::from(-prime), 2.into()]).rational_roots(),
+        vec![(Fraction::from((prime, 2)), 1)]
+    );
+    assert_eq!(Polynomial::from([Fraction::from(prime), 0.into(), 1.into()]).rational_roots(), vec![]);
+
+    // the candidates are tested in exact integer arithmetic, x^5 + 1000000007 and x^4 + 2^40 do not overflow
+    assert_eq!(Polynomial::from([1000000007, 0, 0, 0, 0, 1]).rational_roots(), vec![]);
+    let p = Polynomial::monomial(1.into(), 4) + Polynomial::from(Fraction::from(1i64 << 40));
+    assert_eq!(p.rational_roots(), vec![]);
+
+    // roots and prime factors of the constant term above 2^20: (x - 1048583)(x + 1048601)(1048589 x - 1)
+    let p = Polynomial::from([-1048583, 1]) * Polynomial::from([1048601, 1]) * Polynomial::from([-1, 1048589]);
+    assert_eq!(
+        p.rational_roots(),
+        vec![((-1048601).into(), 1), (Fraction::from((1, 1048589)), 1), (1048583.into(), 1)]
+    );
+
+    // the divisors of a constant term above 2^44 within a root bound above 2^22 take too many trial divisions,
+    // but without real roots there are no candidates
+    let n = 4294967311i128 * 4294967357;
+    assert_eq!(
+        Polynomial::from([Fraction::from(-n), 0.into(), 1.into()]).try_rational_roots(),
+        Err(MatrixError::FactorizationLimit { value: -n })
+    );
+    assert_eq!(Polynomial::from([Fraction::from(n), 0.into(), 1.into()]).try_rational_roots(), Ok(vec![]));
+
+    // only the divisors within the root bound are searched: x^5 - 1000000007 * 4294967311 (x - 3)
+    let p = Polynomial::from([Fraction::from(-1000000007i128 * 4294967311), 0.into(), 0.into(), 0.into(), 0.into(), 1.into()]) * Polynomial::from([-3, 1]);
+    assert_eq!(p.rational_roots(), vec![(3.into(), 1)]);
+}
+
+#[rstest]
+fn format(setup: Fixture) {
+    assert_eq!(format!("{}", setup.zero), "0");
+    assert_eq!(format!("{}", setup.one), "1");
+    assert_eq!(format!("{}", setup.cubic), "x^3 - 6x^2 + 11x - 6");
+    assert_eq!(format!("{}", Polynomial::from([0, -1])), "-x");
+    assert_eq!(
+        format!("{}", Polynomial::from([Fraction::from((1, 2)), 0.into(), Fraction::from((-2, 3))])),
+        "-2/3x^2 + 1/2"
+    );
+}
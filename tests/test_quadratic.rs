@@ -0,0 +1,113 @@
+use mymatrix::{Definiteness, Matrix, MatrixError, QuadraticForm, Vector};
+use pyinrs::Fraction;
+use rstest::{fixture, rstest};
+
+struct Fixture {
+    // 2x^2 - 2xy + 2y^2
+    definite: QuadraticForm,
+    // x^2 + 4xy + y^2
+    indefinite: QuadraticForm,
+    // 2xy + 2xz, only cross terms
+    cross: QuadraticForm,
+    // (x + y)^2
+    degenerate: QuadraticForm,
+}
+
+#[fixture]
+fn setup() -> Fixture {
+    Fixture {
+        definite: QuadraticForm::new(Matrix::from([[2, -1], [-1, 2]])),
+        indefinite: QuadraticForm::new(Matrix::from([[1, 2], [2, 1]])),
+        cross: QuadraticForm::new(Matrix::from([[0, 1, 1], [1, 0, 0], [1, 0, 0]])),
+        degenerate: QuadraticForm::new(Matrix::from([[1, 1], [1, 1]])),
+    }
+}
+
+#[rstest]
+fn basics(setup: Fixture) {
+    assert_eq!(setup.definite.dim(), 2);
+    assert_eq!(setup.cross.matrix(), &Matrix::from([[0, 1, 1], [1, 0, 0], [1, 0, 0]]));
+    assert_eq!(QuadraticForm::try_from(Matrix::identity(2)), Ok(QuadraticForm::new(Matrix::identity(2))));
+
+    assert_eq!(QuadraticForm::try_new(Matrix::from([[1, 2], [0, 1]])), Err(MatrixError::NotSymmetric));
+    assert_eq!(QuadraticForm::try_new(Matrix::from([[1, 2]])), Err(MatrixError::NotSquare { shape: (1, 2) }));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The matrix is not symmetric.")]
+fn bad_new() {
+    QuadraticForm::new(Matrix::from([[1, 2], [0, 1]]));
+}
+
+#[rstest]
+fn eval(setup: Fixture) {
+    let (x, y) = (Vector::from([1, 2]), Vector::from([3, -1]));
+    assert_eq!(setup.definite.eval(&x), 6.into());
+    assert_eq!(setup.indefinite.eval(&Vector::from([1, -1])), (-2).into());
+    assert_eq!(setup.cross.eval(&Vector::from([1, 2, 3])), 10.into());
+    assert_eq!(setup.degenerate.eval(&Vector::from([1, -1])), 0.into());
+    assert_eq!(setup.definite.eval(&Vector::from(vec![Fraction::from((1, 2)), 0.into()])), Fraction::from((1, 2)));
+
+    // symmetric, and q(x + y) = q(x) + 2 b(x, y) + q(y)
+    let q = &setup.indefinite;
+    assert_eq!(q.bilinear(&x, &y), q.bilinear(&y, &x));
+    assert_eq!(q.eval(&(&x + &y)), q.eval(&x) + q.bilinear(&x, &y) * Fraction::from(2) + q.eval(&y));
+
+    assert_eq!(setup.definite.try_eval(&Vector::from([1, 2, 3])), Err(MatrixError::SizeMismatch { lhs: 2, rhs: 3 }));
+    assert_eq!(setup.definite.try_bilinear(&x, &Vector::from([1])), Err(MatrixError::SizeMismatch { lhs: 2, rhs: 1 }));
+}
+
+#[rstest]
+fn diagonalize(setup: Fixture) {
+    for q in [setup.definite, setup.indefinite, setup.cross, setup.degenerate] {
+        let (s, d) = q.diagonalize();
+        assert_eq!(s.transpose() * q.matrix() * &s, d);
+        assert!(d.is_diagonal());
+        assert_ne!(s.det(), 0.into());
+
+        // x = S y gives the sum of squares
+        let y = Vector::from((1..=q.dim() as i32).collect::<Vec<_>>());
+        let x = Vector::from((0..q.dim()).map(|i| &s[i] * &y).collect::<Vec<_>>());
+        assert_eq!(q.eval(&x), (0..q.dim()).fold(Fraction::new(), |acc, i| acc + d[i][i] * y[i] * y[i]));
+    }
+
+    // 2x^2 - 2xy + 2y^2 = 2(x - y/2)^2 + 3/2 y^2
+    let (s, d) = QuadraticForm::new(Matrix::from([[2, -1], [-1, 2]])).diagonalize();
+    assert_eq!(d, Matrix::from(vec![vec![Fraction::from(2), 0.into()], vec![0.into(), Fraction::from((3, 2))]]));
+    assert_eq!(s, Matrix::from(vec![vec![Fraction::from(1), Fraction::from((1, 2))], vec![0.into(), 1.into()]]));
+}
+
+#[rstest]
+fn classify(setup: Fixture) {
+    assert_eq!(setup.definite.definiteness(), Definiteness::PositiveDefinite);
+    assert_eq!(setup.indefinite.definiteness(), Definiteness::Indefinite);
+    assert_eq!(setup.cross.definiteness(), Definiteness::Indefinite);
+    assert_eq!(setup.degenerate.definiteness(), Definiteness::PositiveSemidefinite);
+    assert_eq!(QuadraticForm::new(Matrix::from([[-1, 0], [0, -3]])).definiteness(), Definiteness::NegativeDefinite);
+    assert_eq!(QuadraticForm::new(Matrix::from([[-1, 0], [0, 0]])).definiteness(), Definiteness::NegativeSemidefinite);
+    assert_eq!(QuadraticForm::new(Matrix::zeros(2, 2)).definiteness(), Definiteness::PositiveSemidefinite);
+
+    assert!(!setup.definite.is_degenerate());
+    assert!(!setup.indefinite.is_degenerate());
+    assert!(setup.cross.is_degenerate());
+    assert!(setup.degenerate.is_degenerate());
+}
+
+#[rstest]
+fn rank_and_signature(setup: Fixture) {
+    assert_eq!((setup.definite.rank(), setup.definite.signature()), (2, 2));
+    assert_eq!((setup.indefinite.rank(), setup.indefinite.signature()), (2, 0));
+    assert_eq!(setup.cross.inertia(), (1, 1, 1));
+    assert_eq!((setup.cross.rank(), setup.cross.signature()), (2, 0));
+    assert_eq!((setup.degenerate.rank(), setup.degenerate.signature()), (1, 1));
+    assert_eq!(QuadraticForm::new(Matrix::from([[-1, 0], [0, 0]])).signature(), -1);
+}
+
+#[rstest]
+fn format(setup: Fixture) {
+    assert_eq!(format!("{}", setup.definite), "2x1^2 - 2x1x2 + 2x2^2");
+    assert_eq!(format!("{}", setup.indefinite), "x1^2 + 4x1x2 + x2^2");
+    assert_eq!(format!("{}", setup.cross), "2x1x2 + 2x1x3");
+    assert_eq!(format!("{}", QuadraticForm::new(Matrix::from(vec![vec![Fraction::from((-1, 2))]]))), "-1/2x1^2");
+    assert_eq!(format!("{}", QuadraticForm::new(Matrix::zeros(2, 2))), "0");
+}
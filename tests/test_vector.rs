@@ -1,4 +1,4 @@
-use mymatrix::Vector;
+use mymatrix::{MatrixError, Vector};
 use pyinrs::Fraction;
 use rstest::{fixture, rstest};
 
@@ -115,6 +115,66 @@ fn bad_cross() {
     Vector::cross(&[1].into(), &[2].into());
 }
 
+#[rstest]
+fn primitive(setup: Fixture) {
+    assert_eq!(setup.empty.primitive(), Vector::new());
+    assert_eq!(setup.one.primitive(), Vector::from([1]));
+    assert_eq!(setup.some.primitive(), setup.some);
+
+    assert_eq!(Vector::from([0, 0]).primitive(), Vector::from([0, 0]));
+    assert_eq!(Vector::from([2, 4, 6]).primitive(), Vector::from([1, 2, 3]));
+    assert_eq!(Vector::from([0, -3, 6]).primitive(), Vector::from([0, 1, -2]));
+    assert_eq!(
+        Vector::from([Fraction::from((1, 2)), Fraction::from((-1, 3)), Fraction::from((5, 6))]).primitive(),
+        Vector::from([3, -2, 5])
+    );
+}
+
+#[rstest]
+fn gram_schmidt(setup: Fixture) {
+    let basis = Vector::gram_schmidt(&[Vector::from([1, 1, 0]), Vector::from([1, 0, 1]), Vector::from([2, 1, 1]), Vector::from([0, 1, 1])]);
+    assert_eq!(
+        basis,
+        vec![
+            Vector::from([1, 1, 0]),
+            Vector::from(vec![Fraction::from((1, 2)), Fraction::from((-1, 2)), 1.into()]),
+            Vector::from(vec![Fraction::from((-2, 3)), Fraction::from((2, 3)), Fraction::from((2, 3))]),
+        ]
+    );
+    for i in 0..3 {
+        for j in 0..i {
+            assert!(basis[i].is_orthogonal(&basis[j]));
+        }
+    }
+
+    assert_eq!(Vector::gram_schmidt(&[setup.some.clone(), setup.some.clone()]), vec![setup.some.clone()]);
+    assert_eq!(Vector::gram_schmidt(&[Vector::zeros(2)]), vec![]);
+    assert_eq!(Vector::gram_schmidt(&[]), vec![]);
+    assert_eq!(Vector::try_gram_schmidt(&[setup.one, setup.some]), Err(MatrixError::SizeMismatch { lhs: 1, rhs: 5 }));
+    assert_eq!(Vector::try_gram_schmidt(&[setup.empty.clone(), setup.empty]), Err(MatrixError::Empty));
+}
+
+#[rstest]
+fn projection(setup: Fixture) {
+    let v = Vector::from([3, 4]);
+    let u = Vector::from([1, 1]);
+    assert_eq!(v.project_onto(&u), Vector::from(vec![Fraction::from((7, 2)), Fraction::from((7, 2))]));
+    assert_eq!(v.reject_from(&u), Vector::from(vec![Fraction::from((-1, 2)), Fraction::from((1, 2))]));
+    assert_eq!(v.reflect_across(&u), Vector::from([4, 3]));
+    assert!(v.reject_from(&u).is_orthogonal(&u));
+    assert_eq!(v.project_onto(&u) + v.reject_from(&u), v);
+
+    // across the x axis, and onto the zero vector
+    assert_eq!(v.reflect_across(&Vector::from([2, 0])), Vector::from([3, -4]));
+    assert_eq!(v.project_onto(&Vector::zeros(2)), Vector::zeros(2));
+    assert_eq!(v.reject_from(&Vector::zeros(2)), v);
+    assert_eq!(setup.some.project_onto(&setup.some), setup.some);
+
+    assert_eq!(v.try_project_onto(&setup.some), Err(MatrixError::SizeMismatch { lhs: 2, rhs: 5 }));
+    assert_eq!(setup.empty.try_reject_from(&setup.empty), Err(MatrixError::Empty));
+    assert_eq!(v.try_reflect_across(&setup.one), Err(MatrixError::SizeMismatch { lhs: 2, rhs: 1 }));
+}
+
 #[rstest]
 fn add() {
     assert_eq!(Vector::from([1]) + Vector::from([1]), Vector::from([2]));
@@ -152,6 +212,25 @@ fn dot_mul() {
     assert_eq!(Vector::from([1, 2, 3]) * Vector::from([4, 5, 6]), 32.into());
 }
 
+#[rstest]
+fn try_operations(setup: Fixture) {
+    assert_eq!(Vector::try_cross(&[1, 2].into(), &[3, 4].into()), Ok([-2].into()));
+    assert_eq!(Vector::try_cross(&[1].into(), &[2].into()), Err(MatrixError::IncompatibleCross { lhs: 1, rhs: 1 }));
+    assert_eq!(
+        Vector::try_cross(&[1, 2].into(), &[3, 4, 5].into()),
+        Err(MatrixError::IncompatibleCross { lhs: 2, rhs: 3 })
+    );
+
+    assert_eq!(Vector::from([1, 2, 3]).try_dot(&Vector::from([4, 5, 6])), Ok(32.into()));
+    assert_eq!(setup.empty.try_dot(&setup.empty), Err(MatrixError::Empty));
+    assert_eq!(setup.one.try_dot(&setup.some), Err(MatrixError::SizeMismatch { lhs: 1, rhs: 5 }));
+
+    assert_eq!(setup.some.try_add(&setup.some), Ok(Vector::from([2, 4, 6, 8, 10])));
+    assert_eq!(setup.some.try_add(&setup.one), Err(MatrixError::SizeMismatch { lhs: 5, rhs: 1 }));
+    assert_eq!(setup.some.try_sub(&setup.some), Ok(Vector::zeros(5)));
+    assert_eq!(setup.one.try_sub(&setup.empty), Err(MatrixError::SizeMismatch { lhs: 1, rhs: 0 }));
+}
+
 #[rstest]
 fn format(setup: Fixture) {
     assert_eq!(format!("{}", setup.empty), "[]");